@@ -0,0 +1,554 @@
+//! Generates locale tables from the vendored Unicode CLDR data when the `cldr` feature is on
+
+fn main() {
+    #[cfg(feature = "cldr")]
+    cldr::generate();
+}
+
+#[cfg(feature = "cldr")]
+mod cldr {
+    use std::collections::BTreeMap;
+    use std::env;
+    use std::fmt::Write as _;
+    use std::fs;
+    use std::ops::Index;
+    use std::path::{Path, PathBuf};
+
+    use tinyjson::JsonValue;
+
+    const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+    /// CLDR names of the `Unit` variants, in their order
+    const UNITS: [&str; 10] = [
+        "nanosecond",
+        "microsecond",
+        "millisecond",
+        "second",
+        "minute",
+        "hour",
+        "day",
+        "week",
+        "month",
+        "year",
+    ];
+
+    pub(crate) fn generate() {
+        let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("data/cldr");
+        println!("cargo:rerun-if-changed={}", root.display());
+
+        let plurals = read(&root.join("supplemental/plurals.json"));
+        let plurals = &plurals["supplemental"]["plurals-type-cardinal"];
+        let systems = read(&root.join("supplemental/numberingSystems.json"));
+        let systems = &systems["supplemental"]["numberingSystems"];
+
+        let data = read(&root.join("locales.json"));
+        let data = data.as_object().expect("locales.json is not an object");
+        let mut tags = data.keys().collect::<Vec<_>>();
+        tags.sort_by_key(|tag| tag.to_lowercase());
+
+        let mut rules = BTreeMap::new();
+        let mut locales = String::new();
+        for tag in &tags {
+            let rule = plural_rules(plurals, tag);
+            let count = rules.len();
+            let plural = rules
+                .entry(rule)
+                .or_insert_with(|| format!("plural_{}", count))
+                .clone();
+            let data = Locale::read(&data[tag.as_str()], tag, systems);
+            writeln!(locales, "    ({:?}, {}),", tag, data.emit(&plural)).unwrap();
+        }
+
+        let mut code = String::new();
+        for (rule, name) in &rules {
+            code.push_str(&plural_fn(name, rule));
+        }
+        writeln!(
+            code,
+            "pub(super) static LOCALES: [(&str, Data); {}] = [\n{}];",
+            tags.len(),
+            locales
+        )
+        .unwrap();
+
+        let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("cldr.rs");
+        fs::write(out, code).unwrap();
+    }
+
+    fn read(path: &Path) -> Value {
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+        let value = text
+            .parse::<JsonValue>()
+            .unwrap_or_else(|err| panic!("cannot parse {}: {}", path.display(), err));
+        Value::from(value)
+    }
+
+    /// JSON value of the CLDR data, indexing it with a missing key gives null
+    enum Value {
+        Null,
+        String(String),
+        Object(BTreeMap<String, Value>),
+        /// Numbers, booleans and arrays, none of which the data is read from
+        Other,
+    }
+
+    static NULL: Value = Value::Null;
+
+    impl Value {
+        fn get(&self, key: impl AsRef<str>) -> Option<&Value> {
+            match self {
+                Value::Object(map) => map.get(key.as_ref()),
+                _ => None,
+            }
+        }
+
+        fn as_str(&self) -> Option<&str> {
+            match self {
+                Value::String(text) => Some(text),
+                _ => None,
+            }
+        }
+
+        fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+            match self {
+                Value::Object(map) => Some(map),
+                _ => None,
+            }
+        }
+    }
+
+    impl<K: AsRef<str>> Index<K> for Value {
+        type Output = Value;
+
+        fn index(&self, key: K) -> &Value {
+            self.get(key).unwrap_or(&NULL)
+        }
+    }
+
+    impl From<JsonValue> for Value {
+        fn from(value: JsonValue) -> Self {
+            match value {
+                JsonValue::Null => Value::Null,
+                JsonValue::String(text) => Value::String(text),
+                JsonValue::Object(map) => Value::Object(
+                    map.into_iter()
+                        .map(|(key, value)| (key, value.into()))
+                        .collect(),
+                ),
+                _ => Value::Other,
+            }
+        }
+    }
+
+    /// Returns the plural rules of the `tag` (or its closest parent), keyed by category
+    fn plural_rules(plurals: &Value, tag: &str) -> Vec<(String, String)> {
+        let mut tag = tag;
+        let rules = loop {
+            if let Some(rules) = plurals.get(tag) {
+                break rules;
+            }
+            tag = &tag[..tag.rfind('-').expect("no plural rules")];
+        };
+        CATEGORIES
+            .iter()
+            .filter_map(|category| {
+                let rule = rules.get(format!("pluralRule-count-{}", category))?;
+                let rule = rule.as_str()?.split('@').next()?.trim();
+                Some((category.to_string(), rule.to_string()))
+            })
+            .filter(|(_, rule)| !rule.is_empty())
+            .collect()
+    }
+
+    /// Compiles CLDR plural rules into a function of integer count
+    ///
+    /// Only the integer operands matter for time periods, so the fraction
+    /// and exponent operands (v, w, f, t, c, e) are all folded to zero.
+    fn plural_fn(name: &str, rules: &[(String, String)]) -> String {
+        let mut body = String::new();
+        for (category, rule) in rules {
+            let condition = or_condition(rule);
+            let category = variant(category);
+            match condition.as_str() {
+                "false" => continue,
+                "true" => {
+                    body.push_str(&category);
+                    break;
+                }
+                _ => write!(body, "if {} {{ {} }} else ", condition, category).unwrap(),
+            }
+        }
+        if body.is_empty() {
+            body.push_str("PluralCategory::Other");
+        } else if body.ends_with("else ") {
+            body.push_str("{ PluralCategory::Other }");
+        }
+        let (param, binding) = if body.starts_with("if ") {
+            ("count", "let n = count.unsigned_abs();\n    ")
+        } else {
+            ("_count", "")
+        };
+        format!(
+            "fn {}({}: i64) -> PluralCategory {{\n    {}{}\n}}\n\n",
+            name, param, binding, body
+        )
+    }
+
+    fn variant(category: &str) -> String {
+        let mut chars = category.chars();
+        let first = chars.next().unwrap().to_ascii_uppercase();
+        format!("PluralCategory::{}{}", first, chars.as_str())
+    }
+
+    fn or_condition(rule: &str) -> String {
+        let mut terms = vec![];
+        for and in rule.split(" or ") {
+            match and_condition(and).as_str() {
+                "true" => return "true".into(),
+                "false" => {}
+                term => terms.push(term.to_string()),
+            }
+        }
+        match terms.len() {
+            0 => "false".into(),
+            1 => terms.remove(0),
+            _ => terms
+                .iter()
+                .map(|term| {
+                    if term.contains(" && ") {
+                        format!("({})", term)
+                    } else {
+                        term.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" || "),
+        }
+    }
+
+    fn and_condition(rule: &str) -> String {
+        let mut terms = vec![];
+        for relation in rule.split(" and ") {
+            match relation_condition(relation).as_str() {
+                "false" => return "false".into(),
+                "true" => {}
+                term => terms.push(term.to_string()),
+            }
+        }
+        match terms.len() {
+            0 => "true".into(),
+            1 => terms.remove(0),
+            _ => terms.join(" && "),
+        }
+    }
+
+    fn relation_condition(relation: &str) -> String {
+        let (expr, ranges, negated) = if let Some((expr, ranges)) = relation.split_once("!=") {
+            (expr, ranges, true)
+        } else {
+            let (expr, ranges) = relation.split_once('=').expect("bad plural relation");
+            (expr, ranges, false)
+        };
+
+        let mut expr = expr.split('%').map(str::trim);
+        let operand = expr.next().unwrap();
+        let modulo = expr.next().map(|m| m.parse::<u64>().unwrap());
+        let ranges = ranges
+            .split(',')
+            .map(|range| match range.trim().split_once("..") {
+                Some((low, high)) => (low.parse::<u64>().unwrap(), high.parse().unwrap()),
+                None => {
+                    let value = range.trim().parse().unwrap();
+                    (value, value)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if operand != "n" && operand != "i" {
+            let matched = ranges.iter().any(|&(low, _)| low == 0);
+            return (matched != negated).to_string();
+        }
+
+        let value = match modulo {
+            Some(modulo) => format!("n % {}", modulo),
+            None => "n".into(),
+        };
+        let condition = match ranges.as_slice() {
+            [(low, high)] if low == high => {
+                let op = if negated { "!=" } else { "==" };
+                return format!("{} {} {}", value, op, low);
+            }
+            _ => {
+                let patterns = ranges
+                    .iter()
+                    .map(|(low, high)| {
+                        if low == high {
+                            low.to_string()
+                        } else {
+                            format!("{}..={}", low, high)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!("matches!({}, {})", value, patterns.join(" | "))
+            }
+        };
+        if negated {
+            format!("!{}", condition)
+        } else {
+            condition
+        }
+    }
+
+    /// Message patterns of a single locale
+    struct Locale {
+        now: String,
+        units: Vec<Vec<(String, String)>>,
+        past: Relative,
+        future: Relative,
+        list: [String; 4],
+        number: Number,
+    }
+
+    /// Number format of a single locale
+    struct Number {
+        zero: char,
+        group: Option<char>,
+        decimal: char,
+        grouping: u8,
+        secondary_grouping: u8,
+        min_grouping: u8,
+    }
+
+    /// Relative time patterns of a single tense
+    struct Relative {
+        units: Vec<Option<Vec<(String, String)>>>,
+        prefix: bool,
+        wrapper: Option<String>,
+    }
+
+    impl Locale {
+        fn read(data: &Value, tag: &str, systems: &Value) -> Self {
+            let fields = &data["fields"];
+            let units = &data["units"];
+            let list = &data["list"];
+
+            let units = UNITS
+                .iter()
+                .map(|unit| {
+                    let unit = &units[format!("duration-{}", unit)];
+                    patterns(unit, "unitPattern")
+                        .unwrap_or_else(|| panic!("{}: missing unit patterns", tag))
+                })
+                .collect::<Vec<_>>();
+            let now = fields["second"]["relative-type-0"]
+                .as_str()
+                .expect("missing 'now'")
+                .to_string();
+            let past = Relative::new(fields, "past", &units);
+            let future = Relative::new(fields, "future", &units);
+            let list = ["2", "start", "middle", "end"].map(|key| {
+                list[key]
+                    .as_str()
+                    .unwrap_or_else(|| panic!("{}: missing list pattern", tag))
+                    .to_string()
+            });
+
+            let number = Number::new(&data["numbers"], systems, tag);
+
+            Self {
+                now,
+                units,
+                past,
+                future,
+                list,
+                number,
+            }
+        }
+
+        fn emit(&self, plural: &str) -> String {
+            let units = self
+                .units
+                .iter()
+                .map(|patterns| emit_patterns(patterns))
+                .collect::<Vec<_>>();
+            format!(
+                "Data {{ plural: {}, now: {:?}, units: [{}], past: {}, future: {}, list: List {{ pair: {:?}, start: {:?}, middle: {:?}, end: {:?} }}, number: {} }}",
+                plural,
+                self.now,
+                units.join(", "),
+                self.past.emit(),
+                self.future.emit(),
+                self.list[0],
+                self.list[1],
+                self.list[2],
+                self.list[3],
+                self.number.emit(),
+            )
+        }
+    }
+
+    impl Number {
+        fn new(numbers: &Value, systems: &Value, tag: &str) -> Self {
+            let system = numbers["defaultNumberingSystem"]
+                .as_str()
+                .unwrap_or_else(|| panic!("{}: missing numbering system", tag));
+            let zero = systems[system]["_digits"]
+                .as_str()
+                .and_then(|digits| digits.chars().next())
+                .unwrap_or_else(|| panic!("{}: unknown numbering system {}", tag, system));
+            let symbols = &numbers[format!("symbols-numberSystem-{}", system)];
+            let symbol = |name: &str| {
+                let symbol = symbols[name].as_str().unwrap_or_default();
+                let mut chars = symbol.chars();
+                match (chars.next(), chars.next()) {
+                    (symbol, None) => symbol,
+                    _ => panic!(
+                        "{}: {} symbol {:?} is not a single character",
+                        tag, name, symbol
+                    ),
+                }
+            };
+            let pattern = numbers[format!("decimalFormats-numberSystem-{}", system)]["standard"]
+                .as_str()
+                .unwrap_or_else(|| panic!("{}: missing decimal pattern", tag));
+            let min_grouping = numbers["minimumGroupingDigits"]
+                .as_str()
+                .and_then(|digits| digits.parse().ok())
+                .unwrap_or(1);
+
+            // "#,##,##0.###" has the groups of three digits and then of two
+            let integer = pattern.split('.').next().unwrap();
+            let mut groups = integer.rsplit(',').map(|group| group.len() as u8);
+            let grouping = groups.next().filter(|_| integer.contains(',')).unwrap_or(0);
+            let secondary_grouping = groups.next().filter(|_| integer.matches(',').count() > 1);
+
+            Self {
+                zero,
+                group: symbol("group").filter(|_| grouping > 0),
+                decimal: symbol("decimal").expect("missing decimal separator"),
+                grouping,
+                secondary_grouping: secondary_grouping.unwrap_or(grouping),
+                min_grouping,
+            }
+        }
+
+        fn emit(&self) -> String {
+            format!(
+                "NumberFormat::new({:?}, {:?}).with_digits({:?}).with_grouping({}, {}).with_min_grouping({})",
+                self.group,
+                self.decimal,
+                self.zero,
+                self.grouping,
+                self.secondary_grouping,
+                self.min_grouping
+            )
+        }
+    }
+
+    impl Relative {
+        fn new(fields: &Value, tense: &str, standalone: &[Vec<(String, String)>]) -> Self {
+            let key = format!("relativeTime-type-{}", tense);
+            let units = UNITS
+                .iter()
+                .map(|unit| patterns(&fields[unit][&key], "relativeTimePattern"))
+                .collect::<Vec<_>>();
+
+            // The tense goes in front of the number when most of the patterns say so
+            let (prefixed, total) = units
+                .iter()
+                .flatten()
+                .flatten()
+                .filter(|(_, pattern)| pattern.contains("{0}"))
+                .fold((0, 0), |(prefixed, total), (_, pattern)| {
+                    let prefixed = prefixed + usize::from(!pattern.starts_with("{0}"));
+                    (prefixed, total + 1)
+                });
+            let prefix = prefixed * 2 > total;
+
+            // The most common frame the stand-alone unit patterns appear in,
+            // e.g. "il y a {0}", used for the units CLDR has no relative patterns for
+            let mut wrappers = BTreeMap::<String, usize>::new();
+            for (relative, standalone) in units.iter().zip(standalone) {
+                for (category, pattern) in relative.iter().flatten() {
+                    let unit = &standalone
+                        .iter()
+                        .find(|(c, _)| c == category)
+                        .unwrap_or_else(|| standalone.last().unwrap())
+                        .1;
+                    if unit.contains("{0}") && pattern.contains(unit.as_str()) {
+                        *wrappers
+                            .entry(pattern.replacen(unit.as_str(), "{0}", 1))
+                            .or_default() += 1;
+                    }
+                }
+            }
+            let wrapper = wrappers
+                .into_iter()
+                .max_by_key(|(_, count)| *count)
+                .map(|(wrapper, _)| wrapper);
+
+            Self {
+                units,
+                prefix,
+                wrapper,
+            }
+        }
+
+        fn emit(&self) -> String {
+            let units = self
+                .units
+                .iter()
+                .map(|patterns| match patterns {
+                    Some(patterns) => format!("Some({})", emit_patterns(patterns)),
+                    None => "None".into(),
+                })
+                .collect::<Vec<_>>();
+            format!(
+                "Relative {{ units: [{}], prefix: {}, wrapper: {:?} }}",
+                units.join(", "),
+                self.prefix,
+                self.wrapper
+            )
+        }
+    }
+
+    /// Returns the patterns of a message for every plural category the locale has
+    fn patterns(value: &Value, prefix: &str) -> Option<Vec<(String, String)>> {
+        let patterns = CATEGORIES
+            .iter()
+            .filter_map(|category| {
+                let pattern = value.get(format!("{}-count-{}", prefix, category))?;
+                Some((category.to_string(), pattern.as_str()?.to_string()))
+            })
+            .collect::<Vec<_>>();
+        if patterns.iter().any(|(category, _)| category == "other") {
+            Some(patterns)
+        } else {
+            None
+        }
+    }
+
+    fn emit_patterns(patterns: &[(String, String)]) -> String {
+        let get = |category: &str| {
+            patterns
+                .iter()
+                .find(|(c, _)| c == category)
+                .map(|(_, pattern)| pattern.as_str())
+        };
+        let other = get("other").unwrap();
+        let optional = |category: &str| match get(category) {
+            Some(pattern) if pattern != other => format!("Some({:?})", pattern),
+            _ => "None".into(),
+        };
+        format!(
+            "Patterns {{ zero: {}, one: {}, two: {}, few: {}, many: {}, other: {:?} }}",
+            optional("zero"),
+            optional("one"),
+            optional("two"),
+            optional("few"),
+            optional("many"),
+            other
+        )
+    }
+}
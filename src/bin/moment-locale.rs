@@ -0,0 +1,39 @@
+//! Converts moment.js or dayjs `relativeTime` locale data into a custom locale definition
+//!
+//! Reads the JSON from the file given as the argument, or from the standard input,
+//! and prints the TOML definition `CustomLocale::from_toml()` loads.
+//!
+//! ```text
+//! moment-locale de.json > de.toml
+//! ```
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use chrono_humanize::locale::CustomLocale;
+
+fn main() {
+    let path = env::args().nth(1);
+    let source = match &path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source).map(|_| source)
+        }
+    };
+    let name = path.as_deref().unwrap_or("<stdin>");
+
+    let source = source.unwrap_or_else(|err| {
+        eprintln!("{}: {}", name, err);
+        process::exit(1);
+    });
+    match CustomLocale::from_moment(&source) {
+        Ok(locale) => print!("{}", locale.to_toml()),
+        Err(err) => {
+            eprintln!("{}: {}", name, err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// Error of parsing a text, pointing at the part of it that could not be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    message: &'static str,
+    detail: Option<String>,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    pub(crate) fn new(message: &'static str, span: Range<usize>) -> Self {
+        Self {
+            message,
+            detail: None,
+            span,
+        }
+    }
+
+    /// Adds the `detail` of what went wrong, e.g. the error of the TOML parser
+    #[cfg_attr(not(feature = "custom"), allow(dead_code))]
+    pub(crate) fn with_detail(self, detail: String) -> Self {
+        Self {
+            detail: Some(detail),
+            ..self
+        }
+    }
+
+    /// Returns what went wrong
+    #[must_use]
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the details of what went wrong, if there are any
+    #[must_use]
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    /// Returns the byte range of the offending part of the text
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {}", detail)?;
+        }
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
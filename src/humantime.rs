@@ -1,10 +1,10 @@
-use std::borrow::Cow;
 use std::cmp::max;
 use std::fmt;
 use std::time::SystemTime;
 
 use chrono::{DateTime, Duration, TimeZone, Utc};
 
+use crate::locale::{self, Context, English, Locale, Rounding, Thresholds, Unit};
 use crate::Humanize;
 
 /// Indicates the time of the period in relation to the time of the utterance
@@ -45,6 +45,28 @@ const S_DAY: i64 = S_HOUR * 24;
 const S_WEEK: i64 = S_DAY * 7;
 const S_MONTH: i64 = S_DAY * 30;
 const S_YEAR: i64 = S_DAY * 365;
+// Average Gregorian month and year, which moment.js and dayjs round the counts to
+const S_AVERAGE_MONTH: i64 = 2_629_746;
+const S_AVERAGE_YEAR: i64 = S_AVERAGE_MONTH * 12;
+
+impl Unit {
+    /// Returns the duration of `count` units, `None` if it is too long
+    pub(crate) fn duration(self, count: i64) -> Option<Duration> {
+        let seconds = |unit: i64| count.checked_mul(unit).and_then(Duration::try_seconds);
+        match self {
+            Self::Nanos => Some(Duration::nanoseconds(count)),
+            Self::Micros => Some(Duration::microseconds(count)),
+            Self::Millis => Duration::try_milliseconds(count),
+            Self::Seconds => seconds(1),
+            Self::Minutes => seconds(S_MINUTE),
+            Self::Hours => seconds(S_HOUR),
+            Self::Days => seconds(S_DAY),
+            Self::Weeks => seconds(S_WEEK),
+            Self::Months => seconds(S_MONTH),
+            Self::Years => seconds(S_YEAR),
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 enum TimePeriod {
@@ -63,57 +85,37 @@ enum TimePeriod {
 }
 
 impl TimePeriod {
-    fn to_text_precise(self) -> Cow<'static, str> {
+    fn to_text(self, locale: &dyn Locale, context: Context) -> String {
         match self {
-            Self::Now => "now".into(),
-            Self::Nanos(n) => format!("{} ns", n).into(),
-            Self::Micros(n) => format!("{} µs", n).into(),
-            Self::Millis(n) => format!("{} ms", n).into(),
-            Self::Seconds(1) => "1 second".into(),
-            Self::Seconds(n) => format!("{} seconds", n).into(),
-            Self::Minutes(1) => "1 minute".into(),
-            Self::Minutes(n) => format!("{} minutes", n).into(),
-            Self::Hours(1) => "1 hour".into(),
-            Self::Hours(n) => format!("{} hours", n).into(),
-            Self::Days(1) => "1 day".into(),
-            Self::Days(n) => format!("{} days", n).into(),
-            Self::Weeks(1) => "1 week".into(),
-            Self::Weeks(n) => format!("{} weeks", n).into(),
-            Self::Months(1) => "1 month".into(),
-            Self::Months(n) => format!("{} months", n).into(),
-            Self::Years(1) => "1 year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
-            Self::Eternity => "eternity".into(),
+            Self::Now => locale.now().into_owned(),
+            Self::Nanos(n) => locale.period(Unit::Nanos, n, context),
+            Self::Micros(n) => locale.period(Unit::Micros, n, context),
+            Self::Millis(n) => locale.period(Unit::Millis, n, context),
+            Self::Seconds(n) => locale.period(Unit::Seconds, n, context),
+            Self::Minutes(n) => locale.period(Unit::Minutes, n, context),
+            Self::Hours(n) => locale.period(Unit::Hours, n, context),
+            Self::Days(n) => locale.period(Unit::Days, n, context),
+            Self::Weeks(n) => locale.period(Unit::Weeks, n, context),
+            Self::Months(n) => locale.period(Unit::Months, n, context),
+            Self::Years(n) => locale.period(Unit::Years, n, context),
+            Self::Eternity => locale.eternity().into_owned(),
         }
     }
 
-    fn to_text_rough(self) -> Cow<'static, str> {
+    #[cfg(feature = "icu4x")]
+    fn unit(self) -> Option<(Unit, i64)> {
         match self {
-            Self::Now => "now".into(),
-            Self::Nanos(n) => format!("{} ns", n).into(),
-            Self::Micros(n) => format!("{} µs", n).into(),
-            Self::Millis(n) => format!("{} ms", n).into(),
-            Self::Seconds(n) => format!("{} seconds", n).into(),
-            Self::Minutes(1) => "a minute".into(),
-            Self::Minutes(n) => format!("{} minutes", n).into(),
-            Self::Hours(1) => "an hour".into(),
-            Self::Hours(n) => format!("{} hours", n).into(),
-            Self::Days(1) => "a day".into(),
-            Self::Days(n) => format!("{} days", n).into(),
-            Self::Weeks(1) => "a week".into(),
-            Self::Weeks(n) => format!("{} weeks", n).into(),
-            Self::Months(1) => "a month".into(),
-            Self::Months(n) => format!("{} months", n).into(),
-            Self::Years(1) => "a year".into(),
-            Self::Years(n) => format!("{} years", n).into(),
-            Self::Eternity => "eternity".into(),
-        }
-    }
-
-    fn to_text(self, accuracy: Accuracy) -> Cow<'static, str> {
-        match accuracy {
-            Accuracy::Rough => self.to_text_rough(),
-            Accuracy::Precise => self.to_text_precise(),
+            Self::Now | Self::Eternity => None,
+            Self::Nanos(n) => Some((Unit::Nanos, n)),
+            Self::Micros(n) => Some((Unit::Micros, n)),
+            Self::Millis(n) => Some((Unit::Millis, n)),
+            Self::Seconds(n) => Some((Unit::Seconds, n)),
+            Self::Minutes(n) => Some((Unit::Minutes, n)),
+            Self::Hours(n) => Some((Unit::Hours, n)),
+            Self::Days(n) => Some((Unit::Days, n)),
+            Self::Weeks(n) => Some((Unit::Weeks, n)),
+            Self::Months(n) => Some((Unit::Months, n)),
+            Self::Years(n) => Some((Unit::Years, n)),
         }
     }
 }
@@ -135,31 +137,160 @@ impl HumanTime {
     /// Gives English text representation of the `HumanTime` with given `accuracy` and 'tense`
     #[must_use]
     pub fn to_text_en(self, accuracy: Accuracy, tense: Tense) -> String {
-        let mut periods = match accuracy {
-            Accuracy::Rough => self.rough_period(),
+        self.to_text(&English, accuracy, tense)
+    }
+
+    /// Wraps the `HumanTime` so that it is displayed in the given `locale`,
+    /// the same way `Display` does it in English
+    ///
+    /// ```
+    /// # #[cfg(feature = "locale-de")]
+    /// # {
+    /// use chrono::Duration;
+    /// use chrono_humanize::locale::German;
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let ht = HumanTime::from(Duration::hours(-3));
+    ///
+    /// assert_eq!("vor 3 Stunden", format!("{}", ht.localized(&German)));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn localized(self, locale: &dyn Locale) -> Localized<'_> {
+        Localized { time: self, locale }
+    }
+
+    /// Gives text representation of the `HumanTime` in the locale that suits the BCP-47
+    /// language `tag` best, with given `accuracy` and `tense`
+    ///
+    /// See `locale::Registry` for the details of the locale negotiation.
+    #[must_use]
+    pub fn to_text_for(self, tag: &str, accuracy: Accuracy, tense: Tense) -> String {
+        self.to_text(locale::lookup(tag), accuracy, tense)
+    }
+
+    /// Gives text representation of the `HumanTime` in the given `locale` with given `accuracy`
+    /// and `tense`
+    #[must_use]
+    pub fn to_text(self, locale: &dyn Locale, accuracy: Accuracy, tense: Tense) -> String {
+        let periods = match accuracy {
+            Accuracy::Rough => self.rough_period(locale.thresholds(), locale.rounding()),
             Accuracy::Precise => self.precise_period(),
         };
 
-        let first = periods.remove(0).to_text(accuracy);
-        let last = periods.pop().map(|last| last.to_text(accuracy));
+        let context = Context::new(accuracy, tense);
+        let count = periods.len();
+        let parts = periods
+            .into_iter()
+            .enumerate()
+            .map(|(idx, period)| {
+                let first = idx == 0;
+                let last = idx + 1 == count;
+                period.to_text(
+                    locale,
+                    Context {
+                        first,
+                        last,
+                        ..context
+                    },
+                )
+            })
+            .collect();
+        let text = locale.join(parts);
+
+        match tense {
+            Tense::Past => locale.past(text),
+            Tense::Future => locale.future(text),
+            Tense::Present => locale.present(text),
+        }
+    }
+
+    /// Gives text representation of the `HumanTime` produced by the ICU4X `formatter`
+    /// with given `accuracy` and `tense`
+    ///
+    /// The periods are chosen the same way as for `to_text()` with the default
+    /// thresholds, see `locale::IcuFormatter` for how they are spelled out. ICU4X has no
+    /// text for the eternity, which is taken from the fallback locale of the `formatter`,
+    /// English unless `IcuFormatter::with_fallback()` says otherwise.
+    #[cfg(feature = "icu4x")]
+    #[must_use]
+    pub fn to_text_icu(
+        self,
+        formatter: &locale::IcuFormatter,
+        accuracy: Accuracy,
+        tense: Tense,
+    ) -> String {
+        let periods = match accuracy {
+            Accuracy::Rough => self.rough_period(Thresholds::default(), Rounding::default()),
+            Accuracy::Precise => self.precise_period(),
+        };
 
-        let mut text = periods.into_iter().fold(first, |acc, p| {
-            format!("{}, {}", acc, p.to_text(accuracy)).into()
-        });
+        match periods.as_slice() {
+            [TimePeriod::Now] => formatter.now(),
+            [TimePeriod::Eternity] => formatter.eternity().to_string(),
+            periods => {
+                let periods: Vec<_> = periods.iter().filter_map(|period| period.unit()).collect();
+                formatter.format(&periods, accuracy, tense)
+            }
+        }
+    }
 
-        if let Some(last) = last {
-            text = format!("{} and {}", text, last).into();
+    /// Gives ISO 8601 representation of the `HumanTime`, e.g. "P1Y2M3DT4H5M6.5S"
+    ///
+    /// The duration is split the same way as for the precise text, into 365-day years,
+    /// 30-day months, weeks, days and so on. The weeks are written as days, because
+    /// XML Schema and most of the other formats do not allow them next to the other units.
+    /// Negative durations have the minus sign in front, as in XML Schema.
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let ht = HumanTime::from(Duration::days(45) + Duration::milliseconds(6500));
+    /// assert_eq!("P1M15DT6.5S", ht.to_iso8601());
+    ///
+    /// let ht = HumanTime::from(Duration::hours(-3));
+    /// assert_eq!("-PT3H", ht.to_iso8601());
+    /// ```
+    #[must_use]
+    pub fn to_iso8601(self) -> String {
+        let mut date = String::new();
+        let mut time = String::new();
+        let (mut days, mut seconds, mut nanos) = (0, 0, 0);
+
+        for period in self.precise_period() {
+            match period {
+                TimePeriod::Years(n) => date.push_str(&format!("{}Y", n)),
+                TimePeriod::Months(n) => date.push_str(&format!("{}M", n)),
+                TimePeriod::Weeks(n) => days += n * 7,
+                TimePeriod::Days(n) => days += n,
+                TimePeriod::Hours(n) => time.push_str(&format!("{}H", n)),
+                TimePeriod::Minutes(n) => time.push_str(&format!("{}M", n)),
+                TimePeriod::Seconds(n) => seconds = n,
+                TimePeriod::Millis(n) => nanos += n * 1_000_000,
+                TimePeriod::Micros(n) => nanos += n * 1_000,
+                TimePeriod::Nanos(n) => nanos += n,
+                TimePeriod::Now | TimePeriod::Eternity => {}
+            }
         }
 
-        match tense {
-            Tense::Past => format!("{} ago", text),
-            Tense::Future => format!("in {}", text),
-            Tense::Present => text.into_owned(),
+        if days > 0 {
+            date.push_str(&format!("{}D", days));
+        }
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            time.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds > 0 || date.is_empty() && time.is_empty() {
+            time.push_str(&format!("{}S", seconds));
         }
+
+        let sign = if self.0 < Duration::zero() { "-" } else { "" };
+        let separator = if time.is_empty() { "" } else { "T" };
+        format!("{}P{}{}{}", sign, date, separator, time)
     }
 
-    fn tense(self, accuracy: Accuracy) -> Tense {
-        if accuracy.is_rough() && self.0.num_seconds().abs() < 11 {
+    fn tense(self, accuracy: Accuracy, thresholds: Thresholds) -> Tense {
+        if accuracy.is_rough() && self.0.num_seconds().abs() <= thresholds.now {
             Tense::Present
         } else if self.0 > Duration::zero() {
             Tense::Future
@@ -170,22 +301,32 @@ impl HumanTime {
         }
     }
 
-    fn rough_period(self) -> Vec<TimePeriod> {
-        let period = match self.0.num_seconds().abs() {
-            n if n > 547 * S_DAY => TimePeriod::Years(max(n / S_YEAR, 2)),
-            n if n > 345 * S_DAY => TimePeriod::Years(1),
-            n if n > 45 * S_DAY => TimePeriod::Months(max(n / S_MONTH, 2)),
-            n if n > 29 * S_DAY => TimePeriod::Months(1),
-            n if n > 10 * S_DAY + 12 * S_HOUR => TimePeriod::Weeks(max(n / S_WEEK, 2)),
-            n if n > 6 * S_DAY + 12 * S_HOUR => TimePeriod::Weeks(1),
-            n if n > 36 * S_HOUR => TimePeriod::Days(max(n / S_DAY, 2)),
-            n if n > 22 * S_HOUR => TimePeriod::Days(1),
-            n if n > 90 * S_MINUTE => TimePeriod::Hours(max(n / S_HOUR, 2)),
-            n if n > 45 * S_MINUTE => TimePeriod::Hours(1),
-            n if n > 90 => TimePeriod::Minutes(max(n / S_MINUTE, 2)),
-            n if n > 45 => TimePeriod::Minutes(1),
-            n if n > 10 => TimePeriod::Seconds(n),
-            0..=10 => TimePeriod::Now,
+    fn rough_period(self, t: Thresholds, rounding: Rounding) -> Vec<TimePeriod> {
+        let millis = self.0.num_milliseconds().abs();
+        let (seconds, month, year) = match rounding {
+            Rounding::Down => (self.0.num_seconds().abs(), S_MONTH, S_YEAR),
+            Rounding::Nearest => (nearest(millis, 1000), S_AVERAGE_MONTH, S_AVERAGE_YEAR),
+        };
+        let count = |unit: i64| match rounding {
+            Rounding::Down => seconds / unit,
+            Rounding::Nearest => nearest(millis, unit * 1000),
+        };
+
+        let period = match seconds {
+            n if n > t.years => TimePeriod::Years(max(count(year), 2)),
+            n if n > t.year => TimePeriod::Years(1),
+            n if n > t.months => TimePeriod::Months(max(count(month), 2)),
+            n if n > t.month => TimePeriod::Months(1),
+            n if n > t.weeks => TimePeriod::Weeks(max(count(S_WEEK), 2)),
+            n if n > t.week => TimePeriod::Weeks(1),
+            n if n > t.days => TimePeriod::Days(max(count(S_DAY), 2)),
+            n if n > t.day => TimePeriod::Days(1),
+            n if n > t.hours => TimePeriod::Hours(max(count(S_HOUR), 2)),
+            n if n > t.hour => TimePeriod::Hours(1),
+            n if n > t.minutes => TimePeriod::Minutes(max(count(S_MINUTE), 2)),
+            n if n > t.minute => TimePeriod::Minutes(1),
+            n if n > t.now => TimePeriod::Seconds(n),
+            n if n >= 0 => TimePeriod::Now,
             _ => TimePeriod::Eternity,
         };
 
@@ -333,13 +474,26 @@ impl HumanTime {
         self.0.is_zero()
     }
 
-    fn locale_en(&self, accuracy: Accuracy) -> String {
-        let tense = self.tense(accuracy);
-        self.to_text_en(accuracy, tense)
+    fn localize(self, locale: &dyn Locale, accuracy: Accuracy) -> String {
+        let tense = self.tense(accuracy, locale.thresholds());
+        self.to_text(locale, accuracy, tense)
     }
 }
 
 impl fmt::Display for HumanTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.localized(&English).fmt(f)
+    }
+}
+
+/// `HumanTime` displayed in a locale other than English, see `HumanTime::localized`
+#[derive(Clone, Copy)]
+pub struct Localized<'a> {
+    time: HumanTime,
+    locale: &'a dyn Locale,
+}
+
+impl fmt::Display for Localized<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let accuracy = if f.alternate() {
             Accuracy::Precise
@@ -347,7 +501,15 @@ impl fmt::Display for HumanTime {
             Accuracy::Rough
         };
 
-        f.pad(&self.locale_en(accuracy))
+        f.pad(&self.time.localize(self.locale, accuracy))
+    }
+}
+
+impl fmt::Debug for Localized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localized")
+            .field("time", &self.time)
+            .finish_non_exhaustive()
     }
 }
 
@@ -357,6 +519,12 @@ impl From<Duration> for HumanTime {
     }
 }
 
+impl From<HumanTime> for Duration {
+    fn from(ht: HumanTime) -> Self {
+        ht.0
+    }
+}
+
 impl<TZ> From<DateTime<TZ>> for HumanTime
 where
     TZ: TimeZone,
@@ -392,3 +560,8 @@ impl Humanize for SystemTime {
         HumanTime::from(*self).to_string()
     }
 }
+
+/// Divides `value` by `unit`, rounding half way up as `Math.round()` does
+fn nearest(value: i64, unit: i64) -> i64 {
+    value / unit + i64::from(value % unit >= unit - unit / 2)
+}
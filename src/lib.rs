@@ -38,6 +38,18 @@
 //! assert_eq!("1 month, 2 weeks and 1 day", ht.to_text_en(Accuracy::Precise, Tense::Present));
 //! # }
 //! ```
+//!
+//! Other languages are supported through the `Locale` trait and `HumanTime::to_text()`
+//!
+//! ```
+//! use chrono::Duration;
+//! use chrono_humanize::locale::English;
+//! use chrono_humanize::{Accuracy, HumanTime, Tense};
+//!
+//! let ht = HumanTime::from(Duration::days(45));
+//!
+//! assert_eq!("in a month", ht.to_text(&English, Accuracy::Rough, Tense::Future));
+//! ```
 
 #![cfg_attr(feature = "pedantic", warn(clippy::pedantic))]
 #![warn(clippy::use_self)]
@@ -51,9 +63,15 @@
 #![deny(warnings)]
 #![doc(html_root_url = "https://docs.rs/chrono-humanize/0.2.2")]
 
-pub use crate::humantime::{Accuracy, HumanTime, Tense};
+pub use crate::error::ParseError;
+pub use crate::humantime::{Accuracy, HumanTime, Localized, Tense};
+pub use crate::locale::Locale;
+pub use crate::parse::parse_date;
 
+mod error;
 mod humantime;
+pub mod locale;
+mod parse;
 
 /// Present the object in human friendly text form
 pub trait Humanize {
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use super::{bidi, join_list, number, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Arabic language
+///
+/// Single and dual periods are expressed with the noun alone ("منذ ساعتين"),
+/// all the other counts use the noun form their plural category requires.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Arabic {
+    isolate: bool,
+    native: bool,
+}
+
+impl Arabic {
+    /// Sets whether the numbers and the whole phrase are wrapped into Unicode bidi
+    /// isolates, so the text can be safely embedded into left-to-right text
+    #[must_use]
+    pub const fn isolated(self, isolate: bool) -> Self {
+        Self { isolate, ..self }
+    }
+
+    /// Sets whether the numbers are written with Arabic-Indic digits ("منذ ٣ ساعات")
+    #[must_use]
+    pub const fn native_digits(self, native: bool) -> Self {
+        Self { native, ..self }
+    }
+
+    fn phrase(self, text: String) -> String {
+        if self.isolate {
+            bidi::rtl(text)
+        } else {
+            text
+        }
+    }
+}
+
+impl Locale for Arabic {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::arabic(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        // Dual is in the nominative case when standalone and genitive after a preposition
+        let nominative = context.tense == Tense::Present;
+        let (one, two, few, many, other) = match unit {
+            Unit::Nanos => return "نانو ثانية".into(),
+            Unit::Micros => return "ميكرو ثانية".into(),
+            Unit::Millis => return "مللي ثانية".into(),
+            Unit::Seconds if nominative => ("ثانية", "ثانيتان", "ثوان", "ثانية", "ثانية"),
+            Unit::Seconds => ("ثانية", "ثانيتين", "ثوان", "ثانية", "ثانية"),
+            Unit::Minutes if nominative => ("دقيقة", "دقيقتان", "دقائق", "دقيقة", "دقيقة"),
+            Unit::Minutes => ("دقيقة", "دقيقتين", "دقائق", "دقيقة", "دقيقة"),
+            Unit::Hours if nominative => ("ساعة", "ساعتان", "ساعات", "ساعة", "ساعة"),
+            Unit::Hours => ("ساعة", "ساعتين", "ساعات", "ساعة", "ساعة"),
+            Unit::Days if nominative => ("يوم", "يومان", "أيام", "يومًا", "يوم"),
+            Unit::Days => ("يوم", "يومين", "أيام", "يومًا", "يوم"),
+            Unit::Weeks if nominative => ("أسبوع", "أسبوعان", "أسابيع", "أسبوعًا", "أسبوع"),
+            Unit::Weeks => ("أسبوع", "أسبوعين", "أسابيع", "أسبوعًا", "أسبوع"),
+            Unit::Months if nominative => ("شهر", "شهران", "أشهر", "شهرًا", "شهر"),
+            Unit::Months => ("شهر", "شهرين", "أشهر", "شهرًا", "شهر"),
+            Unit::Years if nominative => ("سنة", "سنتان", "سنوات", "سنة", "سنة"),
+            Unit::Years => ("سنة", "سنتين", "سنوات", "سنة", "سنة"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            PluralCategory::Zero | PluralCategory::Other => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        if self.native {
+            NumberFormat::new(Some('\u{66c}'), '\u{66b}').with_digits(number::ARABIC_INDIC)
+        } else {
+            NumberFormat::default()
+        }
+    }
+
+    fn number(&self, count: i64) -> String {
+        let number = self.number_format().integer(count);
+        if self.isolate {
+            bidi::ltr(number)
+        } else {
+            number
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        let plural = self.plural(count);
+        match plural {
+            PluralCategory::One | PluralCategory::Two if unit > Unit::Millis => {
+                self.unit(unit, plural, context).into_owned()
+            }
+            _ => format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, plural, context)
+            ),
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "الآن".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "الأبد".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, "، ", " و")
+    }
+
+    fn past(&self, text: String) -> String {
+        self.phrase(format!("منذ {}", text))
+    }
+
+    fn future(&self, text: String) -> String {
+        self.phrase(format!("بعد {}", text))
+    }
+
+    fn present(&self, text: String) -> String {
+        self.phrase(text)
+    }
+}
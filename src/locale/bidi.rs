@@ -0,0 +1,23 @@
+//! Unicode bidirectional isolates for the right-to-left locales
+//!
+//! Isolated text does not affect the layout of the text surrounding it, so
+//! "منذ 3 ساعات" embedded into a left-to-right sentence is not visually scrambled.
+
+use std::fmt;
+
+/// LEFT-TO-RIGHT ISOLATE
+const LRI: char = '\u{2066}';
+/// RIGHT-TO-LEFT ISOLATE
+const RLI: char = '\u{2067}';
+/// POP DIRECTIONAL ISOLATE
+const PDI: char = '\u{2069}';
+
+/// Wraps `text` into left-to-right isolate
+pub(crate) fn ltr(text: impl fmt::Display) -> String {
+    format!("{}{}{}", LRI, text, PDI)
+}
+
+/// Wraps `text` into right-to-left isolate
+pub(crate) fn rtl(text: impl fmt::Display) -> String {
+    format!("{}{}{}", RLI, text, PDI)
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use super::{join_list, number, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Bengali language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bengali {
+    native: bool,
+}
+
+impl Bengali {
+    /// Sets whether the numbers are written with Bengali digits ("৩ ঘন্টা আগে")
+    #[must_use]
+    pub const fn native_digits(self, native: bool) -> Self {
+        Self { native }
+    }
+}
+
+impl Locale for Bengali {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::zero_one(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "ন্যানোসেকেন্ড",
+            Unit::Micros => "মাইক্রোসেকেন্ড",
+            Unit::Millis => "মিলিসেকেন্ড",
+            Unit::Seconds => "সেকেন্ড",
+            Unit::Minutes => "মিনিট",
+            Unit::Hours => "ঘন্টা",
+            Unit::Days => "দিন",
+            Unit::Weeks => "সপ্তাহ",
+            Unit::Months => "মাস",
+            Unit::Years => "বছর",
+        };
+        text.into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        let format = NumberFormat::new(Some(','), '.').with_grouping(3, 2);
+        if self.native {
+            format.with_digits(number::BENGALI)
+        } else {
+            format
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("এক {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "এখন".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "অনন্তকাল".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " এবং ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} আগে", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} পরে", text)
+    }
+}
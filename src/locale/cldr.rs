@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use super::{Context, English, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+use self::tables::LOCALES;
+
+/// Tables generated by the build script from the vendored CLDR data
+#[allow(clippy::all)]
+mod tables {
+    use super::{Data, List, NumberFormat, Patterns, PluralCategory, Relative};
+
+    include!(concat!(env!("OUT_DIR"), "/cldr.rs"));
+}
+
+/// Patterns of a single message for every plural category, "{0}" stands for the count
+struct Patterns {
+    zero: Option<&'static str>,
+    one: Option<&'static str>,
+    two: Option<&'static str>,
+    few: Option<&'static str>,
+    many: Option<&'static str>,
+    other: &'static str,
+}
+
+impl Patterns {
+    fn get(&self, plural: PluralCategory) -> &'static str {
+        let pattern = match plural {
+            PluralCategory::Zero => self.zero,
+            PluralCategory::One => self.one,
+            PluralCategory::Two => self.two,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => None,
+        };
+        pattern.unwrap_or(self.other)
+    }
+}
+
+/// Relative time patterns of a single tense
+struct Relative {
+    /// Patterns for every unit CLDR has relative time patterns for
+    units: [Option<Patterns>; 10],
+    /// Whether the tense goes in front of the count, e.g. "in {0} hours"
+    prefix: bool,
+    /// Frame for the stand-alone patterns of the other units, e.g. "in {0}"
+    wrapper: Option<&'static str>,
+}
+
+impl Relative {
+    /// Returns the pattern of the period in the given `context`,
+    /// carrying as much of the tense as belongs to its position in the list
+    fn frame(
+        &self,
+        unit: Unit,
+        plural: PluralCategory,
+        standalone: &str,
+        context: Context,
+    ) -> Cow<'static, str> {
+        let pattern = match (&self.units[unit as usize], self.wrapper) {
+            (Some(patterns), _) => patterns.get(plural).into(),
+            (None, Some(wrapper)) => wrapper.replace("{0}", standalone).into(),
+            (None, None) => standalone.to_string().into(),
+        };
+        if context.first && context.last {
+            return pattern;
+        }
+
+        // The text in front of the stand-alone pattern goes to the first period,
+        // the text after it goes to the last one
+        if let Some(idx) = pattern.find(standalone) {
+            let before = if context.first { &pattern[..idx] } else { "" };
+            let after = if context.last {
+                &pattern[idx + standalone.len()..]
+            } else {
+                ""
+            };
+            return format!("{}{}{}", before, standalone, after).into();
+        }
+
+        // The relative pattern inflects the unit, so it is used as a whole
+        // by the period next to the tense marker, unless the marker surrounds the text
+        match self.wrapper.and_then(|wrapper| wrapper.split_once("{0}")) {
+            Some((before, after)) if !before.is_empty() && !after.is_empty() => {
+                let before = if context.first { before } else { "" };
+                let after = if context.last { after } else { "" };
+                format!("{}{}{}", before, standalone, after).into()
+            }
+            _ if context.first && self.prefix || context.last && !self.prefix => pattern,
+            _ => standalone.to_string().into(),
+        }
+    }
+}
+
+/// List patterns, "{0}" and "{1}" stand for the items joined
+struct List {
+    pair: &'static str,
+    start: &'static str,
+    middle: &'static str,
+    end: &'static str,
+}
+
+struct Data {
+    plural: fn(i64) -> PluralCategory,
+    now: &'static str,
+    units: [Patterns; 10],
+    past: Relative,
+    future: Relative,
+    list: List,
+    number: NumberFormat,
+}
+
+/// Language generated from the Unicode CLDR data
+///
+/// A single period in the past or future tense is spelled out with the CLDR
+/// relative time pattern, e.g. "il y a 3 heures". When there are several periods,
+/// the relative pattern is used for the period next to the tense marker only,
+/// i.e. the first one for the languages putting the tense in front ("il y a 1 heure
+/// et 3 minutes") and the last one for the rest ("1 час и 3 минуты назад").
+/// CLDR has no word for the eternity, which is left in English, so that `Coverage`
+/// reports it as untranslated.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::CldrLocale;
+/// use chrono_humanize::{Accuracy, HumanTime, Tense};
+///
+/// let swedish = CldrLocale::new("sv").unwrap();
+/// let ht = HumanTime::from(Duration::hours(-3));
+///
+/// assert_eq!("för 3 timmar sedan", ht.to_text(&swedish, Accuracy::Rough, Tense::Past));
+/// ```
+#[derive(Clone, Copy)]
+pub struct CldrLocale {
+    tag: &'static str,
+    data: &'static Data,
+}
+
+impl CldrLocale {
+    /// Returns the locale for exactly this CLDR `tag`, e.g. "sr-Latn"
+    #[must_use]
+    pub fn new(tag: &str) -> Option<Self> {
+        let tag = tag.replace('_', "-").to_lowercase();
+        LOCALES
+            .binary_search_by(|(t, _)| t.to_lowercase().cmp(&tag))
+            .ok()
+            .map(Self::at)
+    }
+
+    /// Returns all the locales generated from the CLDR data
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..LOCALES.len()).map(Self::at)
+    }
+
+    /// Returns the CLDR tag of the locale
+    #[must_use]
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    fn at(idx: usize) -> Self {
+        let (tag, ref data) = LOCALES[idx];
+        Self { tag, data }
+    }
+
+    fn relative(&self, tense: Tense) -> Option<&'static Relative> {
+        match tense {
+            Tense::Past => Some(&self.data.past),
+            Tense::Future => Some(&self.data.future),
+            Tense::Present => None,
+        }
+    }
+}
+
+impl fmt::Debug for CldrLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CldrLocale").field(&self.tag).finish()
+    }
+}
+
+impl Locale for CldrLocale {
+    fn plural(&self, count: i64) -> PluralCategory {
+        (self.data.plural)(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let pattern = self.data.units[unit as usize].get(plural);
+        pattern.replace("{0}", "").trim().to_string().into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        self.data.number
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        let plural = self.plural(count);
+        let standalone = self.data.units[unit as usize].get(plural);
+        let pattern = match self.relative(context.tense) {
+            Some(relative) => relative.frame(unit, plural, standalone, context),
+            None => standalone.into(),
+        };
+        pattern.replace("{0}", &self.number(count))
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        self.data.now.into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        English.eternity()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        let list = &self.data.list;
+        let pattern = |pattern: &str, first: &str, second: &str| {
+            pattern.replace("{0}", first).replace("{1}", second)
+        };
+
+        let mut parts = parts;
+        match parts.len() {
+            0 => String::new(),
+            1 => parts.remove(0),
+            2 => pattern(list.pair, &parts[0], &parts[1]),
+            count => {
+                let mut text = pattern(list.end, &parts[count - 2], &parts[count - 1]);
+                for part in parts[1..count - 2].iter().rev() {
+                    text = pattern(list.middle, part, &text);
+                }
+                pattern(list.start, &parts[0], &text)
+            }
+        }
+    }
+
+    fn past(&self, text: String) -> String {
+        text
+    }
+
+    fn future(&self, text: String) -> String {
+        text
+    }
+}
@@ -0,0 +1,210 @@
+use super::{Context, English, Locale, PluralCategory, Unit};
+use crate::{Accuracy, Tense};
+
+/// Whether a locale has its own text for a message
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    /// The text differs from the English one
+    Translated,
+    /// The text is the same as the English one, which is usually a fallback
+    English,
+    /// The text is empty
+    Missing,
+}
+
+/// Text of a single message of a locale
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    key: String,
+    text: String,
+    status: Status,
+}
+
+impl Message {
+    /// Returns the key of the message, e.g. "past" or "period.hours.few"
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the text of the message, "{0}" and "{1}" stand for the texts put into it
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns whether the locale has its own text for the message
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
+
+/// Report on the messages a locale provides
+///
+/// The messages are:
+///
+/// - "now" and "eternity"
+/// - "past" and "future", the text put into the tense
+/// - "list.2" and "list.3", the lists of two and three periods
+/// - "period.<unit>.<plural>", a period of every unit for every plural category
+///   the locale uses, e.g. "period.hours.few" for "3 hours"
+/// - "rough.<unit>", the single period of the rough representation, e.g. "an hour"
+///
+/// The periods are written for the present tense, the same ones written for the past
+/// and future tenses have the keys ending in ".past" and ".future", e.g.
+/// "rough.days.past" for "a day" of "a day ago".
+///
+/// # Examples
+///
+/// ```
+/// use chrono_humanize::locale::{Coverage, English, PseudoLocale};
+///
+/// let english = Coverage::of(&English);
+/// assert!(english.is_complete());
+/// assert!(english.english().any(|message| message.key() == "past"));
+///
+/// let pseudo = Coverage::of(&PseudoLocale::<English>::default());
+/// let past = pseudo.messages().iter().find(|message| message.key() == "past").unwrap();
+/// assert_eq!("[{0} áĝô···]", past.text());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Coverage {
+    messages: Vec<Message>,
+}
+
+impl Coverage {
+    /// Collects the messages of the `locale`
+    #[must_use]
+    pub fn of(locale: &dyn Locale) -> Self {
+        let mut messages = vec![];
+        let mut add = |key: String, text: String, english: String| {
+            let status = if text.trim().is_empty() {
+                Status::Missing
+            } else if text == english {
+                Status::English
+            } else {
+                Status::Translated
+            };
+            messages.push(Message { key, text, status });
+        };
+
+        add("now".into(), locale.now().into(), English.now().into());
+        add(
+            "eternity".into(),
+            locale.eternity().into(),
+            English.eternity().into(),
+        );
+        add(
+            "past".into(),
+            locale.past("{0}".into()),
+            English.past("{0}".into()),
+        );
+        add(
+            "future".into(),
+            locale.future("{0}".into()),
+            English.future("{0}".into()),
+        );
+        for &count in &[2, 3] {
+            let parts = || (0..count).map(|idx| format!("{{{}}}", idx)).collect();
+            add(
+                format!("list.{}", count),
+                locale.join(parts()),
+                English.join(parts()),
+            );
+        }
+
+        // The smallest count of every plural category the locale uses
+        let plurals: Vec<_> = PLURALS
+            .iter()
+            .filter_map(|&plural| {
+                let count = (0..=1000).find(|&count| locale.plural(count) == plural)?;
+                Some((plural, count))
+            })
+            .collect();
+
+        for &unit in UNITS {
+            for &(tense, suffix) in TENSES {
+                let precise = Context::new(Accuracy::Precise, tense);
+                let rough = Context::new(Accuracy::Rough, tense);
+                for &(plural, count) in &plurals {
+                    add(
+                        format!("period.{}.{}{}", unit.name(), plural.name(), suffix),
+                        locale.period(unit, count, precise),
+                        English.period(unit, count, precise),
+                    );
+                }
+                if unit >= Unit::Minutes {
+                    add(
+                        format!("rough.{}{}", unit.name(), suffix),
+                        locale.period(unit, 1, rough),
+                        English.period(unit, 1, rough),
+                    );
+                }
+            }
+        }
+
+        Self { messages }
+    }
+
+    /// Returns all the messages
+    #[must_use]
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns the messages the locale has no text for
+    pub fn missing(&self) -> impl Iterator<Item = &Message> {
+        self.with_status(Status::Missing)
+    }
+
+    /// Returns the messages that are the same as in English
+    pub fn english(&self) -> impl Iterator<Item = &Message> {
+        self.with_status(Status::English)
+    }
+
+    /// Returns whether the locale has a text for every message
+    ///
+    /// The messages that are the same as in English do not count as missing, since
+    /// many are the same by design, e.g. "in {0}" in German or "5 ms" in most languages.
+    /// See `english()` for them.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.missing().next().is_none()
+    }
+
+    fn with_status(&self, status: Status) -> impl Iterator<Item = &Message> {
+        self.messages
+            .iter()
+            .filter(move |message| message.status == status)
+    }
+}
+
+/// The tenses the periods are written for, with the suffixes of their keys
+const TENSES: &[(Tense, &str)] = &[
+    (Tense::Present, ""),
+    (Tense::Past, ".past"),
+    (Tense::Future, ".future"),
+];
+
+const PLURALS: &[PluralCategory] = &[
+    PluralCategory::Zero,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Few,
+    PluralCategory::Many,
+    PluralCategory::Other,
+];
+
+const UNITS: &[Unit] = &[
+    Unit::Nanos,
+    Unit::Micros,
+    Unit::Millis,
+    Unit::Seconds,
+    Unit::Minutes,
+    Unit::Hours,
+    Unit::Days,
+    Unit::Weeks,
+    Unit::Months,
+    Unit::Years,
+];
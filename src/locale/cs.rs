@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Czech language
+///
+/// Time periods are put into the instrumental case in the past tense ("před minutou")
+/// and into the accusative case in the future tense ("za minutu").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Czech;
+
+impl Locale for Czech {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::czech(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let (one, few, other) = match (unit, context.tense) {
+            (Unit::Nanos, _) => return "ns".into(),
+            (Unit::Micros, _) => return "µs".into(),
+            (Unit::Millis, _) => return "ms".into(),
+            (Unit::Seconds, Tense::Past) => ("sekundou", "sekundami", "sekundami"),
+            (Unit::Seconds, Tense::Future) => ("sekundu", "sekundy", "sekund"),
+            (Unit::Seconds, Tense::Present) => ("sekunda", "sekundy", "sekund"),
+            (Unit::Minutes, Tense::Past) => ("minutou", "minutami", "minutami"),
+            (Unit::Minutes, Tense::Future) => ("minutu", "minuty", "minut"),
+            (Unit::Minutes, Tense::Present) => ("minuta", "minuty", "minut"),
+            (Unit::Hours, Tense::Past) => ("hodinou", "hodinami", "hodinami"),
+            (Unit::Hours, Tense::Future) => ("hodinu", "hodiny", "hodin"),
+            (Unit::Hours, Tense::Present) => ("hodina", "hodiny", "hodin"),
+            (Unit::Days, Tense::Past) => ("dnem", "dny", "dny"),
+            (Unit::Days, _) => ("den", "dny", "dní"),
+            (Unit::Weeks, Tense::Past) => ("týdnem", "týdny", "týdny"),
+            (Unit::Weeks, _) => ("týden", "týdny", "týdnů"),
+            (Unit::Months, Tense::Past) => ("měsícem", "měsíci", "měsíci"),
+            (Unit::Months, _) => ("měsíc", "měsíce", "měsíců"),
+            (Unit::Years, Tense::Past) => ("rokem", "lety", "lety"),
+            (Unit::Years, _) => ("rok", "roky", "let"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Seconds {
+            self.unit(unit, PluralCategory::One, context).into_owned()
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "nyní".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "věčnost".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " a ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("před {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("za {}", text)
+    }
+}
@@ -0,0 +1,582 @@
+use std::borrow::Cow;
+use std::cmp::max;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    join_list, Context, English, Locale, NumberFormat, PluralCategory, PluralRules, Rounding,
+    Thresholds, Unit,
+};
+use crate::ParseError;
+
+/// Language defined by a TOML or JSON file loaded at run time
+///
+/// Every part of the definition is optional, the English text is used for the missing
+/// ones. In the texts "{0}" stands for the count, the period or the list of periods.
+///
+/// ```toml
+/// now = "just now"
+/// eternity = "forever"
+/// past = "{0} back"
+/// future = "{0} from now"
+/// present = "{0}"
+///
+/// # How the rough representation rounds the counts, "down" or "nearest"
+/// rounding = "nearest"
+///
+/// # CLDR plural rules, English ones when there are none
+/// [plural]
+/// one = "i = 1 and v = 0"
+///
+/// # Unit names for every plural category the rules produce, "other" is required.
+/// # Names without "{0}" follow the count.
+/// [units]
+/// hours = { one = "hr", other = "hrs" }
+/// days = { one = "day", other = "{0}-day stretch" }
+///
+/// # Single period of the rough representation, or texts of the period for every
+/// # plural category in the rough representation, with "{0}" for the count
+/// [rough]
+/// hours = "an hr"
+/// seconds = { other = "a few secs" }
+///
+/// # Texts of the period for every plural category in the precise representation,
+/// # with "{0}" for the count
+/// [precise]
+/// days = { one = "a day", other = "{0} days" }
+///
+/// [list]
+/// separator = ", "
+/// last = " & "
+///
+/// # Durations, in seconds, the rough representation switches units after
+/// [thresholds]
+/// now = 5
+///
+/// # How the counts are written, "1,234" by default
+/// [number]
+/// zero = "๐"
+/// group_separator = " "
+/// decimal_separator = ","
+/// ```
+///
+/// The units are named "nanos", "micros", "millis", "seconds", "minutes", "hours", "days",
+/// "weeks", "months" and "years". See `Thresholds` for the names of the thresholds
+/// and `NumberFormat` for the names of the number settings.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::CustomLocale;
+/// use chrono_humanize::HumanTime;
+///
+/// let casual = CustomLocale::from_toml(r#"
+///     past = "{0} back"
+///     units.hours = { one = "hr", other = "hrs" }
+/// "#).unwrap();
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+/// assert_eq!("3 hrs back", format!("{}", ht.localized(&casual)));
+/// ```
+#[derive(Debug)]
+pub struct CustomLocale {
+    definition: Definition,
+    plurals: PluralRules,
+}
+
+impl CustomLocale {
+    /// Loads the locale from the TOML `source`
+    pub fn from_toml(source: &str) -> Result<Self, ParseError> {
+        let definition = toml::from_str(source).map_err(|err: toml::de::Error| {
+            let span = err.span().unwrap_or(0..source.len());
+            ParseError::new("invalid TOML", span).with_detail(err.message().to_string())
+        })?;
+        Self::new(source, definition)
+    }
+
+    /// Loads the locale from the JSON `source`
+    pub fn from_json(source: &str) -> Result<Self, ParseError> {
+        let definition = serde_json::from_str(source).map_err(json_error(source))?;
+        Self::new(source, definition)
+    }
+
+    /// Converts the moment.js or dayjs `relativeTime` object in the JSON `source`
+    ///
+    /// The `source` is either the `relativeTime` object itself or a locale
+    /// configuration object that has it. The texts are used word for word and the
+    /// counts are rounded to the nearest whole number, as both libraries do. The units
+    /// are switched at their default thresholds, in which they agree, or at the limits
+    /// of the dayjs relativeTime plugin `thresholds` option, when the configuration
+    /// object has that array next to `relativeTime`. Weeks are used only when there are
+    /// texts for them. moment.js has no texts for the precise representation, so "a
+    /// minute" and "%d minutes" and alike are used there, the seconds are always "%d
+    /// seconds".
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use chrono_humanize::locale::CustomLocale;
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let german = CustomLocale::from_moment(r#"{
+    ///     "future": "in %s", "past": "vor %s", "s": "ein paar Sekunden",
+    ///     "m": "einer Minute", "mm": "%d Minuten", "h": "einer Stunde", "hh": "%d Stunden",
+    ///     "d": "einem Tag", "dd": "%d Tagen", "M": "einem Monat", "MM": "%d Monaten",
+    ///     "y": "einem Jahr", "yy": "%d Jahren"
+    /// }"#).unwrap();
+    ///
+    /// let ht = HumanTime::from(Duration::seconds(-30));
+    /// assert_eq!("vor ein paar Sekunden", format!("{}", ht.localized(&german)));
+    /// let ht = HumanTime::from(Duration::minutes(-160));
+    /// assert_eq!("vor 3 Stunden", format!("{}", ht.localized(&german)));
+    /// let ht = HumanTime::from(Duration::hours(-1));
+    /// assert_eq!("vor einer Stunde", format!("{:#}", ht.localized(&german)));
+    /// ```
+    pub fn from_moment(source: &str) -> Result<Self, ParseError> {
+        let value: serde_json::Value = serde_json::from_str(source).map_err(json_error(source))?;
+        let (relative_time, limits) = match value.get("relativeTime") {
+            Some(relative_time) => (relative_time.clone(), value.get("thresholds").cloned()),
+            None => (value, None),
+        };
+        let moment: Moment = serde_json::from_value(relative_time).map_err(|err| {
+            ParseError::new("invalid moment.js locale", 0..source.len())
+                .with_detail(err.to_string())
+        })?;
+        let limits: Vec<Limit> = match limits {
+            Some(limits) => serde_json::from_value(limits).map_err(|err| {
+                ParseError::new("invalid dayjs thresholds", 0..source.len())
+                    .with_detail(err.to_string())
+            })?,
+            None => vec![],
+        };
+        Self::new(source, moment.into_definition(&limits))
+    }
+
+    /// Returns the TOML definition of the locale, the one `from_toml()` loads
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        // Strings and integers are all there is to serialize
+        toml::to_string(&self.definition).expect("locale definition is valid TOML")
+    }
+
+    fn new(source: &str, definition: Definition) -> Result<Self, ParseError> {
+        let plural = &definition.plural;
+        let rules = [
+            (PluralCategory::Zero, &plural.zero),
+            (PluralCategory::One, &plural.one),
+            (PluralCategory::Two, &plural.two),
+            (PluralCategory::Few, &plural.few),
+            (PluralCategory::Many, &plural.many),
+        ];
+
+        let mut plurals = PluralRules::new();
+        if rules.iter().all(|(_, rule)| rule.is_none()) {
+            plurals = plurals.rule(PluralCategory::One, "i = 1 and v = 0")?;
+        }
+        for (category, rule) in rules.iter() {
+            if let Some(rule) = rule {
+                plurals = plurals.rule(*category, rule).map_err(|err| {
+                    // Point into the source, the rules need no escaping to be found there
+                    let start = source.find(rule.as_str()).unwrap_or(0);
+                    let span = err.span();
+                    ParseError::new(err.message(), start + span.start..start + span.end)
+                })?;
+            }
+        }
+
+        Ok(Self {
+            definition,
+            plurals,
+        })
+    }
+}
+
+impl Locale for CustomLocale {
+    fn plural(&self, count: i64) -> PluralCategory {
+        self.plurals.select(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        match self.definition.units.get(unit) {
+            Some(forms) => forms
+                .get(plural)
+                .replace("{0}", "")
+                .trim()
+                .to_string()
+                .into(),
+            None => English.unit(unit, plural, context).into_owned().into(),
+        }
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        self.definition.number
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() {
+            match self.definition.rough.get(unit) {
+                Some(Rough::Single(text)) if count == 1 => return text.clone(),
+                Some(Rough::Forms(forms)) => {
+                    let form = forms.get(self.plural(count));
+                    return form.replace("{0}", &self.number(count));
+                }
+                _ => (),
+            }
+        } else if let Some(forms) = self.definition.precise.get(unit) {
+            return forms
+                .get(self.plural(count))
+                .replace("{0}", &self.number(count));
+        }
+
+        match self.definition.units.get(unit) {
+            Some(forms) => {
+                let name = forms.get(self.plural(count));
+                let number = self.number(count);
+                if name.contains("{0}") {
+                    name.replace("{0}", &number)
+                } else {
+                    format!("{} {}", number, name)
+                }
+            }
+            None if context.accuracy.is_rough() && count == 1 => {
+                English.period(unit, count, context)
+            }
+            None => {
+                let name = English.unit(unit, English.plural(count), context);
+                format!("{} {}", self.number(count), name)
+            }
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        match &self.definition.now {
+            Some(now) => now.into(),
+            None => English.now().into_owned().into(),
+        }
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        match &self.definition.eternity {
+            Some(eternity) => eternity.into(),
+            None => English.eternity().into_owned().into(),
+        }
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        let list = &self.definition.list;
+        let separator = list.separator.as_deref().unwrap_or(", ");
+        let last = list.last.as_deref().unwrap_or(" and ");
+        join_list(parts, separator, last)
+    }
+
+    fn past(&self, text: String) -> String {
+        match &self.definition.past {
+            Some(past) => past.replace("{0}", &text),
+            None => English.past(text),
+        }
+    }
+
+    fn future(&self, text: String) -> String {
+        match &self.definition.future {
+            Some(future) => future.replace("{0}", &text),
+            None => English.future(text),
+        }
+    }
+
+    fn present(&self, text: String) -> String {
+        match &self.definition.present {
+            Some(present) => present.replace("{0}", &text),
+            None => text,
+        }
+    }
+
+    fn thresholds(&self) -> Thresholds {
+        self.definition.thresholds
+    }
+
+    fn rounding(&self) -> Rounding {
+        self.definition.rounding
+    }
+}
+
+/// Returns the conversion of JSON errors into the errors pointing into the `source`
+fn json_error(source: &str) -> impl Fn(serde_json::Error) -> ParseError + '_ {
+    move |err| {
+        // Lines and columns are counted from 1, the column is in bytes
+        let line = source
+            .split_inclusive('\n')
+            .take(err.line().saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>();
+        let offset = (line + err.column().saturating_sub(1)).min(source.len());
+        let message = err.to_string();
+        let message = match message.rsplit_once(" at line ") {
+            Some((message, _)) => message.to_string(),
+            None => message,
+        };
+        ParseError::new("invalid JSON", offset..offset).with_detail(message)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Definition {
+    now: Option<String>,
+    eternity: Option<String>,
+    past: Option<String>,
+    future: Option<String>,
+    present: Option<String>,
+    rounding: Rounding,
+    plural: Plural,
+    units: PerUnit<Forms>,
+    rough: PerUnit<Rough>,
+    precise: PerUnit<Forms>,
+    list: List,
+    thresholds: Thresholds,
+    number: NumberFormat,
+}
+
+/// Plural rules for every category but "other"
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Plural {
+    zero: Option<String>,
+    one: Option<String>,
+    two: Option<String>,
+    few: Option<String>,
+    many: Option<String>,
+}
+
+/// Text for every plural category
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Forms {
+    zero: Option<String>,
+    one: Option<String>,
+    two: Option<String>,
+    few: Option<String>,
+    many: Option<String>,
+    other: String,
+}
+
+impl Forms {
+    fn get(&self, plural: PluralCategory) -> &str {
+        let form = match plural {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => &None,
+        };
+        form.as_deref().unwrap_or(&self.other)
+    }
+}
+
+/// Text of the rough representation
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+enum Rough {
+    /// Text of the single period, e.g. "an hour"
+    Single(String),
+    /// Texts for every count
+    Forms(Forms),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct PerUnit<T> {
+    nanos: Option<T>,
+    micros: Option<T>,
+    millis: Option<T>,
+    seconds: Option<T>,
+    minutes: Option<T>,
+    hours: Option<T>,
+    days: Option<T>,
+    weeks: Option<T>,
+    months: Option<T>,
+    years: Option<T>,
+}
+
+impl<T> PerUnit<T> {
+    fn get(&self, unit: Unit) -> Option<&T> {
+        let value = match unit {
+            Unit::Nanos => &self.nanos,
+            Unit::Micros => &self.micros,
+            Unit::Millis => &self.millis,
+            Unit::Seconds => &self.seconds,
+            Unit::Minutes => &self.minutes,
+            Unit::Hours => &self.hours,
+            Unit::Days => &self.days,
+            Unit::Weeks => &self.weeks,
+            Unit::Months => &self.months,
+            Unit::Years => &self.years,
+        };
+        value.as_ref()
+    }
+}
+
+// Not derived, so that `T` does not have to implement `Default`
+impl<T> Default for PerUnit<T> {
+    fn default() -> Self {
+        Self {
+            nanos: None,
+            micros: None,
+            millis: None,
+            seconds: None,
+            minutes: None,
+            hours: None,
+            days: None,
+            weeks: None,
+            months: None,
+            years: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct List {
+    separator: Option<String>,
+    last: Option<String>,
+}
+
+/// The `relativeTime` object of moment.js and dayjs locales
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Moment {
+    future: Option<String>,
+    past: Option<String>,
+    s: Option<String>,
+    ss: Option<String>,
+    m: Option<String>,
+    mm: Option<String>,
+    h: Option<String>,
+    hh: Option<String>,
+    d: Option<String>,
+    dd: Option<String>,
+    w: Option<String>,
+    ww: Option<String>,
+    #[serde(rename = "M")]
+    month: Option<String>,
+    #[serde(rename = "MM")]
+    months: Option<String>,
+    y: Option<String>,
+    yy: Option<String>,
+}
+
+/// Row of the `thresholds` option of the dayjs relativeTime plugin
+///
+/// The unit of the row, `d`, is left out, the rows are told apart by their texts.
+#[derive(Debug, Deserialize)]
+struct Limit {
+    /// Key of the text, e.g. "mm"
+    l: String,
+    /// Largest rounded count the text is used for, none for any count
+    r: Option<i64>,
+}
+
+impl Moment {
+    fn into_definition(self, limits: &[Limit]) -> Definition {
+        const MINUTE: i64 = 60;
+        const HOUR: i64 = 60 * MINUTE;
+        const DAY: i64 = 24 * HOUR;
+        const MONTH: i64 = 2_629_746;
+        const YEAR: i64 = 12 * MONTH;
+
+        let pattern =
+            |text: Option<String>| text.map(|text| text.replace("%s", "{0}").replace("%d", "{0}"));
+        let forms = |one: &Option<String>, other: &Option<String>| {
+            let other = pattern(other.clone())?;
+            let one = pattern(one.clone());
+            Some(Forms {
+                zero: None,
+                one,
+                two: None,
+                few: None,
+                many: None,
+                other,
+            })
+        };
+        let rough =
+            |one: &Option<String>, other: &Option<String>| forms(one, other).map(Rough::Forms);
+
+        // The limits default to the ones of dayjs, which switch the units where moment.js
+        // does, and a row without one takes everything longer. The counts are rounded to
+        // the nearest whole number, so a limit of `count` units holds until half a unit
+        // more, and a single unit until one and a half.
+        let limit = |key: &str, default: i64| match limits.iter().find(|limit| limit.l == key) {
+            Some(limit) => limit.r.unwrap_or(i64::MAX),
+            None => default,
+        };
+        let upto = |count: i64, unit: i64| count.saturating_mul(unit).saturating_add(unit / 2 - 1);
+
+        let month = upto(limit("dd", 25), DAY);
+        let weeks = self.w.is_some() || self.ww.is_some();
+        let thresholds = Thresholds {
+            // Even no time at all is "a few seconds ago"
+            now: -1,
+            minute: limit("s", 44),
+            minutes: max(limit("m", 89), upto(1, MINUTE)),
+            hour: upto(limit("mm", 44), MINUTE),
+            hours: max(upto(limit("h", 89), MINUTE), upto(1, HOUR)),
+            day: upto(limit("hh", 21), HOUR),
+            days: max(upto(limit("d", 35), HOUR), upto(1, DAY)),
+            week: if weeks { upto(6, DAY) } else { month },
+            weeks: if weeks { upto(10, DAY) } else { month },
+            month,
+            months: max(upto(limit("M", 45), DAY), upto(1, MONTH)),
+            year: upto(limit("MM", 10), MONTH),
+            years: max(upto(limit("y", 17), MONTH), upto(1, YEAR)),
+        };
+
+        let past = pattern(self.past);
+        Definition {
+            now: pattern(self.s.clone()),
+            eternity: None,
+            present: past.clone().or_else(|| Some(English.past("{0}".into()))),
+            past,
+            future: pattern(self.future),
+            rounding: Rounding::Nearest,
+            plural: Plural {
+                one: Some("n = 1".into()),
+                ..Plural::default()
+            },
+            units: PerUnit {
+                seconds: forms(&None, &self.ss),
+                minutes: forms(&None, &self.mm),
+                hours: forms(&None, &self.hh),
+                days: forms(&None, &self.dd),
+                weeks: forms(&None, &self.ww),
+                months: forms(&None, &self.months),
+                years: forms(&None, &self.yy),
+                ..PerUnit::default()
+            },
+            rough: PerUnit {
+                seconds: rough(&None, &self.s),
+                minutes: rough(&self.m, &self.mm),
+                hours: rough(&self.h, &self.hh),
+                days: rough(&self.d, &self.dd),
+                weeks: rough(&self.w, &self.ww),
+                months: rough(&self.month, &self.months),
+                years: rough(&self.y, &self.yy),
+                ..PerUnit::default()
+            },
+            // There is no singular text for the seconds, "s" is "a few seconds"
+            precise: PerUnit {
+                seconds: forms(&None, &self.ss),
+                minutes: forms(&self.m, &self.mm),
+                hours: forms(&self.h, &self.hh),
+                days: forms(&self.d, &self.dd),
+                weeks: forms(&self.w, &self.ww),
+                months: forms(&self.month, &self.months),
+                years: forms(&self.y, &self.yy),
+                ..PerUnit::default()
+            },
+            list: List::default(),
+            thresholds,
+            // "%d" is not grouped
+            number: NumberFormat::new(None, '.'),
+        }
+    }
+}
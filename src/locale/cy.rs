@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, PluralCategory, Unit};
+
+/// Welsh language
+///
+/// Units take soft or nasal mutation depending on the count
+/// ("2 flynedd", "3 blynedd", "10 mlynedd").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Welsh;
+
+impl Locale for Welsh {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::welsh(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (zero, one, two, few, many, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("eiliad", "eiliad", "eiliad", "eiliad", "eiliad", "eiliad"),
+            Unit::Minutes => ("munud", "munud", "funud", "munud", "munud", "munud"),
+            Unit::Hours => ("awr", "awr", "awr", "awr", "awr", "awr"),
+            Unit::Days => (
+                "diwrnod", "diwrnod", "ddiwrnod", "diwrnod", "diwrnod", "diwrnod",
+            ),
+            Unit::Weeks => (
+                "wythnos", "wythnos", "wythnos", "wythnos", "wythnos", "wythnos",
+            ),
+            Unit::Months => ("mis", "mis", "fis", "mis", "mis", "mis"),
+            Unit::Years => (
+                "mlynedd", "flwyddyn", "flynedd", "blynedd", "blynedd", "mlynedd",
+            ),
+        };
+
+        match plural {
+            PluralCategory::Zero => zero,
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            PluralCategory::Other => other,
+        }
+        .into()
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "nawr".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "tragwyddoldeb".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " a ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} yn ôl", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("ymhen {}", text)
+    }
+}
@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// German language
+///
+/// Both "vor" and "in" require the dative case ("vor 3 Tagen", "in 3 Tagen"),
+/// while the standalone periods are in the nominative case ("3 Tage").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct German;
+
+impl Locale for German {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let dative = context.tense != Tense::Present;
+        let (one, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("Sekunde", "Sekunden"),
+            Unit::Minutes => ("Minute", "Minuten"),
+            Unit::Hours => ("Stunde", "Stunden"),
+            Unit::Days if dative => ("Tag", "Tagen"),
+            Unit::Days => ("Tag", "Tage"),
+            Unit::Weeks => ("Woche", "Wochen"),
+            Unit::Months if dative => ("Monat", "Monaten"),
+            Unit::Months => ("Monat", "Monate"),
+            Unit::Years if dative => ("Jahr", "Jahren"),
+            Unit::Years => ("Jahr", "Jahre"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            let dative = context.tense != Tense::Present;
+            let article = match unit {
+                Unit::Days | Unit::Months | Unit::Years if dative => "einem",
+                Unit::Days | Unit::Months | Unit::Years => "ein",
+                _ if dative => "einer",
+                _ => "eine",
+            };
+            format!(
+                "{} {}",
+                article,
+                self.unit(unit, PluralCategory::One, context)
+            )
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "jetzt".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "Ewigkeit".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " und ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("vor {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("in {}", text)
+    }
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, PluralCategory, Unit};
+
+/// English language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Locale for English {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let one = plural == PluralCategory::One;
+        let text = match unit {
+            Unit::Nanos => "ns",
+            Unit::Micros => "µs",
+            Unit::Millis => "ms",
+            Unit::Seconds if one => "second",
+            Unit::Seconds => "seconds",
+            Unit::Minutes if one => "minute",
+            Unit::Minutes => "minutes",
+            Unit::Hours if one => "hour",
+            Unit::Hours => "hours",
+            Unit::Days if one => "day",
+            Unit::Days => "days",
+            Unit::Weeks if one => "week",
+            Unit::Weeks => "weeks",
+            Unit::Months if one => "month",
+            Unit::Months => "months",
+            Unit::Years if one => "year",
+            Unit::Years => "years",
+        };
+        text.into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        match unit {
+            Unit::Hours if context.accuracy.is_rough() && count == 1 => "an hour".into(),
+            Unit::Minutes | Unit::Days | Unit::Weeks | Unit::Months | Unit::Years
+                if context.accuracy.is_rough() && count == 1 =>
+            {
+                format!("a {}", self.unit(unit, PluralCategory::One, context))
+            }
+            _ => format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            ),
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "now".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eternity".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " and ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} ago", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("in {}", text)
+    }
+}
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Finnish language
+///
+/// The case of every period depends on the tense: nominative or partitive
+/// in the past tense ("3 tuntia sitten") and genitive in the future tense
+/// ("3 tunnin päästä").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Finnish;
+
+impl Locale for Finnish {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let (nominative, partitive, genitive) = match unit {
+            Unit::Nanos => ("nanosekunti", "nanosekuntia", "nanosekunnin"),
+            Unit::Micros => ("mikrosekunti", "mikrosekuntia", "mikrosekunnin"),
+            Unit::Millis => ("millisekunti", "millisekuntia", "millisekunnin"),
+            Unit::Seconds => ("sekunti", "sekuntia", "sekunnin"),
+            Unit::Minutes => ("minuutti", "minuuttia", "minuutin"),
+            Unit::Hours => ("tunti", "tuntia", "tunnin"),
+            Unit::Days => ("päivä", "päivää", "päivän"),
+            Unit::Weeks => ("viikko", "viikkoa", "viikon"),
+            Unit::Months => ("kuukausi", "kuukautta", "kuukauden"),
+            Unit::Years => ("vuosi", "vuotta", "vuoden"),
+        };
+
+        match (context.tense, plural) {
+            (Tense::Future, _) => genitive,
+            (_, PluralCategory::One) => nominative,
+            _ => partitive,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            self.unit(unit, PluralCategory::One, context).into_owned()
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "nyt".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "ikuisuus".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " ja ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} sitten", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} päästä", text)
+    }
+}
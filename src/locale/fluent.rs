@@ -0,0 +1,226 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use intl_pluralrules::{PluralCategory as Category, PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+use super::{Context, English, Locale, PluralCategory, Unit};
+use crate::{Accuracy, ParseError, Tense};
+
+/// Language defined by Project Fluent messages
+///
+/// The texts come from the following messages of the bundle. Every message is
+/// optional, the English text is used for the missing ones, except that a
+/// missing `humantime-period` is made of the count and the `humantime-unit`.
+///
+/// | message | arguments | English |
+/// |---------|-----------|---------|
+/// | `humantime-period` | `unit`, `count`, `tense`, `accuracy` | "3 hours" |
+/// | `humantime-unit` | `unit`, `plural` | "hours" |
+/// | `humantime-now` | | "now" |
+/// | `humantime-eternity` | | "eternity" |
+/// | `humantime-list` | `head`, `tail` | "{ $head }, { $tail }" |
+/// | `humantime-list-end` | `head`, `tail` | "{ $head } and { $tail }" |
+/// | `humantime-past` | `text` | "{ $text } ago" |
+/// | `humantime-future` | `text` | "in { $text }" |
+/// | `humantime-present` | `text` | "{ $text }" |
+///
+/// The `unit` is one of "nanos", "micros", "millis", "seconds", "minutes", "hours",
+/// "days", "weeks", "months" and "years", the `count` is a number the plural
+/// variants can be selected on, the `tense` is "past", "present" or "future" and
+/// the `accuracy` is "rough" or "precise". The `plural` is the name of the CLDR
+/// plural category, e.g. "one" or "other".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::FluentLocale;
+/// use chrono_humanize::HumanTime;
+///
+/// let ftl = r#"
+/// humantime-period = { $count ->
+///     [one] { $unit ->
+///         [hours] une heure
+///        *[other] un { $unit }
+///     }
+///    *[other] { $count } { $unit ->
+///         [hours] heures
+///        *[other] { $unit }
+///     }
+/// }
+/// humantime-past = il y a { $text }
+/// "#;
+/// let french = FluentLocale::from_ftl("fr", ftl).unwrap();
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+/// assert_eq!("il y a 3 heures", format!("{}", ht.localized(&french)));
+/// ```
+pub struct FluentLocale {
+    bundle: FluentBundle<FluentResource>,
+    plurals: PluralRules,
+}
+
+impl FluentLocale {
+    /// Creates locale from the `bundle`, the plural rules come from its first locale
+    #[must_use]
+    pub fn new(bundle: FluentBundle<FluentResource>) -> Self {
+        let plurals = bundle
+            .locales
+            .first()
+            .and_then(|langid| PluralRules::create(langid.clone(), PluralRuleType::CARDINAL).ok())
+            .unwrap_or_else(|| {
+                let english =
+                    LanguageIdentifier::from_parts("en".parse().unwrap(), None, None, &[]);
+                PluralRules::create(english, PluralRuleType::CARDINAL).unwrap()
+            });
+        Self { bundle, plurals }
+    }
+
+    /// Creates locale from the Fluent `source` written for the language `tag`
+    ///
+    /// Unicode isolation marks are not put around the arguments.
+    pub fn from_ftl(tag: &str, source: &str) -> Result<Self, ParseError> {
+        let langid = tag
+            .parse::<LanguageIdentifier>()
+            .map_err(|_| ParseError::new("invalid language tag", 0..tag.len()))?;
+        let resource = FluentResource::try_new(source.to_string()).map_err(|(_, errors)| {
+            let span = errors.first().map_or(0..0, |error| error.pos.clone());
+            ParseError::new("invalid Fluent syntax", span)
+        })?;
+
+        let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource_overriding(resource);
+        Ok(Self::new(bundle))
+    }
+
+    /// Returns the bundle the messages come from
+    #[must_use]
+    pub fn bundle(&self) -> &FluentBundle<FluentResource> {
+        &self.bundle
+    }
+
+    fn message(&self, id: &str, args: Option<&FluentArgs<'_>>) -> Option<String> {
+        let pattern = self.bundle.get_message(id)?.value()?;
+        let mut errors = vec![];
+        let text = self.bundle.format_pattern(pattern, args, &mut errors);
+        Some(text.into_owned())
+    }
+
+    fn tense(&self, id: &str, text: String, fallback: impl FnOnce(String) -> String) -> String {
+        let mut args = FluentArgs::new();
+        args.set("text", text.as_str());
+        self.message(id, Some(&args))
+            .unwrap_or_else(|| fallback(text))
+    }
+}
+
+impl fmt::Debug for FluentLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FluentLocale")
+            .field("locales", &self.bundle.locales)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Locale for FluentLocale {
+    fn plural(&self, count: i64) -> PluralCategory {
+        match self.plurals.select(count).ok() {
+            Some(Category::ZERO) => PluralCategory::Zero,
+            Some(Category::ONE) => PluralCategory::One,
+            Some(Category::TWO) => PluralCategory::Two,
+            Some(Category::FEW) => PluralCategory::Few,
+            Some(Category::MANY) => PluralCategory::Many,
+            Some(Category::OTHER) | None => PluralCategory::Other,
+        }
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let mut args = FluentArgs::new();
+        args.set("unit", unit.name());
+        args.set("plural", plural.name());
+        match self.message("humantime-unit", Some(&args)) {
+            Some(text) => text.into(),
+            None => English.unit(unit, plural, context).into_owned().into(),
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        let mut args = FluentArgs::new();
+        args.set("unit", unit.name());
+        args.set("count", FluentValue::from(count));
+        args.set("tense", tense_name(context.tense));
+        args.set("accuracy", accuracy_name(context.accuracy));
+        self.message("humantime-period", Some(&args))
+            .unwrap_or_else(|| {
+                let plural = self.plural(count);
+                format!(
+                    "{} {}",
+                    self.number(count),
+                    self.unit(unit, plural, context)
+                )
+            })
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        self.message("humantime-now", None)
+            .map_or_else(|| English.now().into_owned().into(), Cow::Owned)
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        self.message("humantime-eternity", None)
+            .map_or_else(|| English.eternity().into_owned().into(), Cow::Owned)
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        let count = parts.len();
+        let mut parts = parts.into_iter().enumerate();
+        let mut text = parts.next().map(|(_, part)| part).unwrap_or_default();
+
+        for (idx, part) in parts {
+            let (id, separator) = if idx + 1 == count {
+                ("humantime-list-end", " and ")
+            } else {
+                ("humantime-list", ", ")
+            };
+            let mut args = FluentArgs::new();
+            args.set("head", text.as_str());
+            args.set("tail", part.as_str());
+            text = self
+                .message(id, Some(&args))
+                .unwrap_or_else(|| format!("{}{}{}", text, separator, part));
+        }
+
+        text
+    }
+
+    fn past(&self, text: String) -> String {
+        self.tense("humantime-past", text, |text| English.past(text))
+    }
+
+    fn future(&self, text: String) -> String {
+        self.tense("humantime-future", text, |text| English.future(text))
+    }
+
+    fn present(&self, text: String) -> String {
+        self.tense("humantime-present", text, |text| text)
+    }
+}
+
+fn tense_name(tense: Tense) -> &'static str {
+    match tense {
+        Tense::Past => "past",
+        Tense::Present => "present",
+        Tense::Future => "future",
+    }
+}
+
+fn accuracy_name(accuracy: Accuracy) -> &'static str {
+    match accuracy {
+        Accuracy::Rough => "rough",
+        Accuracy::Precise => "precise",
+    }
+}
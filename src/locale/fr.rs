@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// French language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct French;
+
+impl Locale for French {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::zero_one(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("seconde", "secondes"),
+            Unit::Minutes => ("minute", "minutes"),
+            Unit::Hours => ("heure", "heures"),
+            Unit::Days => ("jour", "jours"),
+            Unit::Weeks => ("semaine", "semaines"),
+            Unit::Months => ("mois", "mois"),
+            Unit::Years => ("an", "ans"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{202f}'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            let article = match unit {
+                Unit::Days | Unit::Months | Unit::Years => "un",
+                _ => "une",
+            };
+            format!(
+                "{} {}",
+                article,
+                self.unit(unit, PluralCategory::One, context)
+            )
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "maintenant".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "éternité".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " et ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("il y a {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("dans {}", text)
+    }
+}
@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, PluralCategory, Unit};
+
+/// Irish language
+///
+/// Units are lenited or eclipsed depending on the count ("2 bhliain", "7 mbliana").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Irish;
+
+impl Locale for Irish {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::irish(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, two, few, many, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("soicind", "shoicind", "shoicind", "soicind", "soicind"),
+            Unit::Minutes => ("nóiméad", "nóiméad", "nóiméad", "nóiméad", "nóiméad"),
+            Unit::Hours => (
+                "uair an chloig",
+                "uair an chloig",
+                "uair an chloig",
+                "n-uair an chloig",
+                "uair an chloig",
+            ),
+            Unit::Days => ("lá", "lá", "lá", "lá", "lá"),
+            Unit::Weeks => (
+                "seachtain",
+                "sheachtain",
+                "seachtaine",
+                "seachtaine",
+                "seachtain",
+            ),
+            Unit::Months => ("mhí", "mhí", "mhí", "mí", "mí"),
+            Unit::Years => ("bhliain", "bhliain", "bliana", "mbliana", "bliain"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "anois".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "síoraíocht".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " agus ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} ó shin", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("i gceann {}", text)
+    }
+}
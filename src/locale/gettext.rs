@@ -0,0 +1,868 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use super::{Context, Locale, PluralCategory, Unit};
+use crate::ParseError;
+
+/// Language defined by a gettext message catalog
+///
+/// The catalog translates the English texts, so every message missing from it
+/// stays in English, the way gettext does it. The message ids are:
+///
+/// - "now" and "eternity"
+/// - "%d second"/"%d seconds", "%d minute"/"%d minutes", "%d hour"/"%d hours",
+///   "%d day"/"%d days", "%d week"/"%d weeks", "%d month"/"%d months" and
+///   "%d year"/"%d years", as well as "%d ns", "%d µs" and "%d ms" (both forms)
+/// - "a minute", "an hour", "a day", "a week", "a month" and "a year" for the
+///   single period of the rough representation
+/// - "%s, %s" and "%s and %s" for joining the periods
+/// - "%s ago" and "in %s"
+///
+/// The translations may refer to the arguments by their positions, e.g. "%2$s, %1$s".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::{Catalog, GettextLocale};
+/// use chrono_humanize::HumanTime;
+///
+/// let po = r#"
+/// msgid ""
+/// msgstr "Plural-Forms: nplurals=2; plural=(n > 1);\n"
+///
+/// msgid "%d hour"
+/// msgid_plural "%d hours"
+/// msgstr[0] "%d heure"
+/// msgstr[1] "%d heures"
+///
+/// msgid "%s ago"
+/// msgstr "il y a %s"
+/// "#;
+/// let french = GettextLocale::new(Catalog::from_po(po).unwrap());
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+/// assert_eq!("il y a 3 heures", format!("{}", ht.localized(&french)));
+/// ```
+#[derive(Debug)]
+pub struct GettextLocale {
+    catalog: Catalog,
+}
+
+impl GettextLocale {
+    /// Creates locale translated by the `catalog`
+    #[must_use]
+    pub fn new(catalog: Catalog) -> Self {
+        Self { catalog }
+    }
+
+    /// Returns the catalog the translations come from
+    #[must_use]
+    pub fn catalog(&self) -> &Catalog {
+        &self.catalog
+    }
+}
+
+impl Locale for GettextLocale {
+    /// Gettext plural forms are not CLDR categories, so the first form is reported
+    /// as "one" and all the others as "other"
+    fn plural(&self, count: i64) -> PluralCategory {
+        let forms = &self.catalog.plural_forms;
+        if forms.nplurals > 1 && forms.index(count.unsigned_abs()) == 0 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let count = if plural == PluralCategory::One { 1 } else { 2 };
+        let (singular, plural) = unit_messages(unit);
+        let text = self.catalog.ngettext(singular, plural, count);
+        printf(text, &[], "").trim().to_string().into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 {
+            let article = match unit {
+                Unit::Minutes => Some("a minute"),
+                Unit::Hours => Some("an hour"),
+                Unit::Days => Some("a day"),
+                Unit::Weeks => Some("a week"),
+                Unit::Months => Some("a month"),
+                Unit::Years => Some("a year"),
+                _ => None,
+            };
+            if let Some(msgid) = article {
+                return self.catalog.gettext(msgid).to_string();
+            }
+        }
+
+        let (singular, plural) = unit_messages(unit);
+        let text = self
+            .catalog
+            .ngettext(singular, plural, count.unsigned_abs());
+        printf(text, &[], &self.number(count))
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        self.catalog.gettext("now").into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        self.catalog.gettext("eternity").into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        let count = parts.len();
+        let mut parts = parts.into_iter().enumerate();
+        let mut text = parts.next().map(|(_, part)| part).unwrap_or_default();
+
+        for (idx, part) in parts {
+            let msgid = if idx + 1 == count {
+                "%s and %s"
+            } else {
+                "%s, %s"
+            };
+            text = printf(self.catalog.gettext(msgid), &[&text, &part], "");
+        }
+
+        text
+    }
+
+    fn past(&self, text: String) -> String {
+        printf(self.catalog.gettext("%s ago"), &[&text], "")
+    }
+
+    fn future(&self, text: String) -> String {
+        printf(self.catalog.gettext("in %s"), &[&text], "")
+    }
+}
+
+fn unit_messages(unit: Unit) -> (&'static str, &'static str) {
+    match unit {
+        Unit::Nanos => ("%d ns", "%d ns"),
+        Unit::Micros => ("%d µs", "%d µs"),
+        Unit::Millis => ("%d ms", "%d ms"),
+        Unit::Seconds => ("%d second", "%d seconds"),
+        Unit::Minutes => ("%d minute", "%d minutes"),
+        Unit::Hours => ("%d hour", "%d hours"),
+        Unit::Days => ("%d day", "%d days"),
+        Unit::Weeks => ("%d week", "%d weeks"),
+        Unit::Months => ("%d month", "%d months"),
+        Unit::Years => ("%d year", "%d years"),
+    }
+}
+
+/// Substitutes the `%s` (or positional `%1$s`) placeholders with `args`
+/// and the `%d` ones with `number`
+fn printf(format: &str, args: &[&str], number: &str) -> String {
+    let mut text = String::with_capacity(format.len());
+    let mut next = 0;
+    let mut rest = format;
+
+    while let Some(idx) = rest.find('%') {
+        text.push_str(&rest[..idx]);
+        rest = &rest[idx + 1..];
+
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let position = if digits > 0 && rest[digits..].starts_with('$') {
+            let position = rest[..digits].parse::<usize>().ok();
+            rest = &rest[digits + 1..];
+            position.and_then(|position| position.checked_sub(1))
+        } else {
+            None
+        };
+
+        match rest.chars().next() {
+            Some('s') => {
+                let idx = position.unwrap_or_else(|| {
+                    next += 1;
+                    next - 1
+                });
+                text.push_str(args.get(idx).copied().unwrap_or_default());
+                rest = &rest[1..];
+            }
+            Some('d') => {
+                text.push_str(number);
+                rest = &rest[1..];
+            }
+            Some('%') => {
+                text.push('%');
+                rest = &rest[1..];
+            }
+            _ => text.push('%'),
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
+
+/// Translations of messages, loaded from a gettext `.po` or `.mo` file
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+    messages: HashMap<String, Vec<String>>,
+    plural_forms: PluralForms,
+}
+
+impl Catalog {
+    /// Loads the catalog from the text of a `.po` file
+    ///
+    /// Fuzzy and untranslated entries are skipped. The plural forms come from
+    /// the "Plural-Forms" header and default to the English ones.
+    pub fn from_po(text: &str) -> Result<Self, ParseError> {
+        PoParser::new(text).parse()
+    }
+
+    /// Loads the catalog from the contents of a `.mo` file
+    pub fn from_mo(bytes: &[u8]) -> Result<Self, ParseError> {
+        let word = |offset: usize, little: bool| -> Result<usize, ParseError> {
+            let bytes = bytes
+                .get(offset..offset.saturating_add(4))
+                .ok_or_else(|| ParseError::new("unexpected end of file", offset..bytes.len()))?;
+            let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            let word = if little {
+                u32::from_le_bytes(bytes)
+            } else {
+                u32::from_be_bytes(bytes)
+            };
+            Ok(word as usize)
+        };
+
+        let little = match word(0, true)? {
+            0x9504_12de => true,
+            0xde12_0495 => false,
+            _ => return Err(ParseError::new("not a .mo file", 0..4)),
+        };
+        let count = word(8, little)?;
+        let originals = word(12, little)?;
+        let translations = word(16, little)?;
+
+        let string = |table: usize, idx: usize| -> Result<&str, ParseError> {
+            let entry = table + idx * 8;
+            let len = word(entry, little)?;
+            let offset = word(entry + 4, little)?;
+            let bytes = bytes
+                .get(offset..offset.saturating_add(len))
+                .ok_or_else(|| ParseError::new("string out of bounds", entry..entry + 8))?;
+            std::str::from_utf8(bytes)
+                .map_err(|_| ParseError::new("invalid UTF-8", offset..offset + len))
+        };
+
+        let mut catalog = Self::default();
+        for idx in 0..count {
+            let msgid = string(originals, idx)?;
+            let msgstr = string(translations, idx)?;
+            // The plural msgid follows the singular one after a NUL
+            let msgid = msgid.split('\0').next().unwrap_or_default();
+            let forms = msgstr.split('\0').map(str::to_string).collect();
+            let entry = translations + idx * 8;
+            catalog.insert(msgid.to_string(), forms, entry..entry + 8)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Returns the translation of the `msgid`, or the `msgid` itself
+    #[must_use]
+    pub fn gettext<'a>(&'a self, msgid: &'a str) -> &'a str {
+        self.messages
+            .get(msgid)
+            .and_then(|forms| forms.first())
+            .map_or(msgid, String::as_str)
+    }
+
+    /// Returns the translation of the `msgid` in the plural form for `n`,
+    /// or the `msgid` or `msgid_plural` itself
+    #[must_use]
+    pub fn ngettext<'a>(&'a self, msgid: &'a str, msgid_plural: &'a str, n: u64) -> &'a str {
+        let translation = self
+            .messages
+            .get(msgid)
+            .and_then(|forms| forms.get(self.plural_forms.index(n)));
+        match translation {
+            Some(translation) => translation,
+            None if n == 1 => msgid,
+            None => msgid_plural,
+        }
+    }
+
+    /// Returns the plural forms of the catalog language
+    #[must_use]
+    pub fn plural_forms(&self) -> &PluralForms {
+        &self.plural_forms
+    }
+
+    /// Adds the message, the header entry (`span`) sets the plural forms
+    fn insert(
+        &mut self,
+        msgid: String,
+        forms: Vec<String>,
+        span: Range<usize>,
+    ) -> Result<(), ParseError> {
+        if msgid.is_empty() {
+            let header = forms.first().map_or("", String::as_str);
+            if let Some(line) = header
+                .lines()
+                .find_map(|line| line.strip_prefix("Plural-Forms:"))
+            {
+                // The header is unescaped, so point at its entry as a whole
+                self.plural_forms = line
+                    .parse()
+                    .map_err(|err: ParseError| ParseError::new(err.message(), span))?;
+            }
+        } else if forms.iter().any(|form| !form.is_empty()) {
+            self.messages.insert(msgid, forms);
+        }
+        Ok(())
+    }
+}
+
+/// Parser of the `.po` file format
+struct PoParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+/// Single entry of a `.po` file
+#[derive(Default)]
+struct PoEntry {
+    span: Range<usize>,
+    fuzzy: bool,
+    context: Option<String>,
+    msgid: Option<String>,
+    msgid_plural: Option<String>,
+    forms: Vec<String>,
+}
+
+impl PoEntry {
+    fn field(&mut self, keyword: Keyword, idx: usize) -> &mut String {
+        match keyword {
+            Keyword::Context => self.context.get_or_insert_with(String::new),
+            Keyword::Id => self.msgid.get_or_insert_with(String::new),
+            Keyword::IdPlural => self.msgid_plural.get_or_insert_with(String::new),
+            Keyword::Str => {
+                if self.forms.len() <= idx {
+                    self.forms.resize(idx + 1, String::new());
+                }
+                &mut self.forms[idx]
+            }
+        }
+    }
+
+    /// Adds the entry to the `catalog`, unless it is fuzzy
+    fn flush(self, catalog: &mut Catalog) -> Result<(), ParseError> {
+        let msgid = match self.msgid {
+            Some(msgid) if !self.fuzzy || msgid.is_empty() => msgid,
+            _ => return Ok(()),
+        };
+        let msgid = match self.context {
+            Some(context) => format!("{}\u{4}{}", context, msgid),
+            None => msgid,
+        };
+        catalog.insert(msgid, self.forms, self.span)
+    }
+}
+
+impl<'a> PoParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Catalog, ParseError> {
+        let mut catalog = Catalog::default();
+        let mut entry = PoEntry::default();
+        // The string the continuation lines are appended to
+        let mut current = None;
+
+        while let Some((line, span)) = self.line() {
+            let offset = span.start + (line.len() - line.trim_start().len());
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('"') {
+                let (keyword, idx) =
+                    current.ok_or_else(|| ParseError::new("unexpected string", span))?;
+                entry.field(keyword, idx).push_str(&unquote(line, offset)?);
+                continue;
+            }
+
+            let starts_entry =
+                line.starts_with('#') || line.starts_with("msgctxt") || line.starts_with("msgid ");
+            if starts_entry && entry.msgid.is_some() {
+                std::mem::take(&mut entry).flush(&mut catalog)?;
+            }
+            if entry.msgid.is_none() && entry.context.is_none() && !entry.fuzzy {
+                entry.span = span.clone();
+            }
+            entry.span.end = span.end;
+
+            if let Some(comment) = line.strip_prefix('#') {
+                if comment.starts_with(',') && comment.contains("fuzzy") {
+                    entry.fuzzy = true;
+                }
+                current = None;
+                continue;
+            }
+
+            let (keyword, idx, value) = keyword(line, offset)?;
+            let value_offset = offset + (line.len() - value.len());
+            entry
+                .field(keyword, idx)
+                .push_str(&unquote(value, value_offset)?);
+            current = Some((keyword, idx));
+        }
+
+        entry.flush(&mut catalog)?;
+        Ok(catalog)
+    }
+
+    /// Returns the next line and its byte range
+    fn line(&mut self) -> Option<(&'a str, Range<usize>)> {
+        if self.pos >= self.text.len() {
+            return None;
+        }
+        let start = self.pos;
+        let rest = &self.text[start..];
+        let len = rest.find('\n').unwrap_or(rest.len());
+        self.pos = start + len + 1;
+        let line = rest[..len].trim_end_matches('\r');
+        Some((line, start..start + line.len()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Keyword {
+    Context,
+    Id,
+    IdPlural,
+    Str,
+}
+
+/// Splits the line into the keyword, the plural form index and the rest
+fn keyword(line: &str, offset: usize) -> Result<(Keyword, usize, &str), ParseError> {
+    let end = line.find(char::is_whitespace).unwrap_or(line.len());
+    let (word, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    let keyword = match word {
+        "msgctxt" => (Keyword::Context, 0, rest),
+        "msgid" => (Keyword::Id, 0, rest),
+        "msgid_plural" => (Keyword::IdPlural, 0, rest),
+        "msgstr" => (Keyword::Str, 0, rest),
+        _ => {
+            let idx = word
+                .strip_prefix("msgstr[")
+                .and_then(|word| word.strip_suffix(']'))
+                .filter(|idx| !idx.is_empty() && idx.bytes().all(|b| b.is_ascii_digit()))
+                .ok_or_else(|| ParseError::new("unknown keyword", offset..offset + end))?;
+            let start = offset + "msgstr[".len();
+            let idx = idx
+                .parse()
+                .ok()
+                .filter(|&idx| idx < MAX_FORMS)
+                .ok_or_else(|| {
+                    ParseError::new("plural form index too large", start..start + idx.len())
+                })?;
+            (Keyword::Str, idx, rest)
+        }
+    };
+    Ok(keyword)
+}
+
+/// The most plural forms a message may have, CLDR needs six at most
+const MAX_FORMS: usize = 16;
+
+/// Returns the contents of the C string literal at `offset`
+fn unquote(literal: &str, offset: usize) -> Result<String, ParseError> {
+    let error = |message| ParseError::new(message, offset..offset + literal.len());
+    let inner = literal
+        .strip_prefix('"')
+        .and_then(|literal| literal.strip_suffix('"'))
+        .filter(|_| literal.len() >= 2)
+        .ok_or_else(|| error("expected quoted string"))?;
+
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('r') => text.push('\r'),
+            Some('"') => text.push('"'),
+            Some('\\') => text.push('\\'),
+            _ => return Err(error("invalid escape sequence")),
+        }
+    }
+    Ok(text)
+}
+
+/// Plural forms of a gettext catalog, e.g. "nplurals=2; plural=(n != 1);"
+///
+/// ```
+/// use chrono_humanize::locale::PluralForms;
+///
+/// let polish: PluralForms = "nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(3, polish.nplurals());
+/// assert_eq!(vec![0, 1, 2, 2, 1], [1, 2, 5, 12, 22].iter().map(|&n| polish.index(n)).collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PluralForms {
+    nplurals: usize,
+    plural: Expr,
+}
+
+impl PluralForms {
+    /// Returns the number of plural forms
+    #[must_use]
+    pub fn nplurals(&self) -> usize {
+        self.nplurals
+    }
+
+    /// Returns the index of the plural form for `n`
+    #[must_use]
+    pub fn index(&self, n: u64) -> usize {
+        let index = self.plural.eval(n);
+        usize::try_from(index)
+            .ok()
+            .filter(|&index| index < self.nplurals)
+            .unwrap_or(0)
+    }
+}
+
+impl Default for PluralForms {
+    /// Plural forms of English
+    fn default() -> Self {
+        let n = Box::new(Expr::N);
+        let one = Box::new(Expr::Number(1));
+        Self {
+            nplurals: 2,
+            plural: Expr::Binary(Op::Ne, n, one),
+        }
+    }
+}
+
+impl FromStr for PluralForms {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut nplurals = None;
+        let mut plural = None;
+        let mut offset = 0;
+
+        for part in text.split(';') {
+            let start = offset + (part.len() - part.trim_start().len());
+            offset += part.len() + 1;
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| ParseError::new("expected assignment", start..start + part.len()))?;
+            let value_start = start + name.len() + 1;
+            match name.trim() {
+                "nplurals" => {
+                    let value = value.trim().parse::<usize>().ok().filter(|&n| n > 0);
+                    let span = value_start..start + part.len();
+                    nplurals =
+                        Some(value.ok_or_else(|| ParseError::new("invalid nplurals", span))?);
+                }
+                "plural" => {
+                    plural = Some(ExprParser::new(text, value_start, start + part.len()).parse()?)
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        "unknown parameter",
+                        start..start + name.len(),
+                    ))
+                }
+            }
+        }
+
+        match (nplurals, plural) {
+            (Some(nplurals), Some(plural)) => Ok(Self { nplurals, plural }),
+            (None, _) => Err(ParseError::new("missing nplurals", 0..text.len())),
+            (_, None) => Err(ParseError::new("missing plural", 0..text.len())),
+        }
+    }
+}
+
+impl fmt::Display for PluralForms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nplurals={}; plural={};", self.nplurals, self.plural)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Or => 1,
+            Self::And => 2,
+            Self::Eq | Self::Ne => 3,
+            Self::Lt | Self::Le | Self::Gt | Self::Ge => 4,
+            Self::Add | Self::Sub => 5,
+            Self::Mul | Self::Div | Self::Rem => 6,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Or => "||",
+            Self::And => "&&",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+        }
+    }
+}
+
+/// C expression of the "plural" parameter
+#[derive(Clone, Debug)]
+enum Expr {
+    N,
+    Number(u64),
+    Not(Box<Self>),
+    Binary(Op, Box<Self>, Box<Self>),
+    Ternary(Box<Self>, Box<Self>, Box<Self>),
+}
+
+impl Expr {
+    fn eval(&self, n: u64) -> u64 {
+        match self {
+            Self::N => n,
+            Self::Number(number) => *number,
+            Self::Not(expr) => u64::from(expr.eval(n) == 0),
+            Self::Binary(op, left, right) => {
+                let left = left.eval(n);
+                // Both operands of the logical operators are not always evaluated in C,
+                // but they have no side effects here
+                let right = right.eval(n);
+                match op {
+                    Op::Or => u64::from(left != 0 || right != 0),
+                    Op::And => u64::from(left != 0 && right != 0),
+                    Op::Eq => u64::from(left == right),
+                    Op::Ne => u64::from(left != right),
+                    Op::Lt => u64::from(left < right),
+                    Op::Le => u64::from(left <= right),
+                    Op::Gt => u64::from(left > right),
+                    Op::Ge => u64::from(left >= right),
+                    Op::Add => left.wrapping_add(right),
+                    Op::Sub => left.wrapping_sub(right),
+                    Op::Mul => left.wrapping_mul(right),
+                    Op::Div => left.checked_div(right).unwrap_or(0),
+                    Op::Rem => left.checked_rem(right).unwrap_or(0),
+                }
+            }
+            Self::Ternary(condition, then, otherwise) => {
+                if condition.eval(n) != 0 {
+                    then.eval(n)
+                } else {
+                    otherwise.eval(n)
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::N => f.write_str("n"),
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Not(expr) => write!(f, "!{}", expr),
+            Self::Binary(op, left, right) => write!(f, "({} {} {})", left, op.symbol(), right),
+            Self::Ternary(condition, then, otherwise) => {
+                write!(f, "({} ? {} : {})", condition, then, otherwise)
+            }
+        }
+    }
+}
+
+/// The deepest nesting of the plural expression, far beyond what any language needs
+const MAX_DEPTH: usize = 64;
+
+/// Precedence climbing parser of the C expression of the plural forms
+struct ExprParser<'a> {
+    text: &'a str,
+    pos: usize,
+    end: usize,
+    /// How many subexpressions the parser is inside of
+    depth: usize,
+}
+
+impl<'a> ExprParser<'a> {
+    fn new(text: &'a str, start: usize, end: usize) -> Self {
+        Self {
+            text,
+            pos: start,
+            end,
+            depth: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<Expr, ParseError> {
+        let expr = self.ternary()?;
+        self.skip_whitespace();
+        if self.pos < self.end {
+            return Err(self.error("unexpected character"));
+        }
+        Ok(expr)
+    }
+
+    fn ternary(&mut self) -> Result<Expr, ParseError> {
+        self.nested(Self::conditional)
+    }
+
+    fn conditional(&mut self) -> Result<Expr, ParseError> {
+        let condition = self.binary(1)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let then = self.ternary()?;
+        if !self.eat(":") {
+            return Err(self.error("expected ':'"));
+        }
+        let otherwise = self.ternary()?;
+        Ok(Expr::Ternary(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// Parses the subexpression with `parse`, as long as the expression is not nested
+    /// too deeply for the recursion to exhaust the stack
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Expr, ParseError>,
+    ) -> Result<Expr, ParseError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error("expression nested too deeply"));
+        }
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn binary(&mut self, precedence: u8) -> Result<Expr, ParseError> {
+        let mut left = self.unary()?;
+        while let Some(op) = self.peek_op().filter(|op| op.precedence() >= precedence) {
+            self.eat(op.symbol());
+            let right = self.binary(op.precedence() + 1)?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        self.skip_whitespace();
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.nested(Self::unary)?)));
+        }
+        if self.eat("(") {
+            let expr = self.ternary()?;
+            if !self.eat(")") {
+                return Err(self.error("expected ')'"));
+            }
+            return Ok(expr);
+        }
+        if self.eat("n") {
+            return Ok(Expr::N);
+        }
+        let rest = self.rest();
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits > 0 {
+            let span = self.pos..self.pos + digits;
+            let number = rest[..digits]
+                .parse()
+                .map_err(|_| ParseError::new("number too large", span))?;
+            self.pos += digits;
+            return Ok(Expr::Number(number));
+        }
+        Err(self.error("expected expression"))
+    }
+
+    fn peek_op(&mut self) -> Option<Op> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let ops = [
+            Op::Or,
+            Op::And,
+            Op::Eq,
+            Op::Ne,
+            Op::Le,
+            Op::Ge,
+            Op::Lt,
+            Op::Gt,
+            Op::Add,
+            Op::Sub,
+            Op::Mul,
+            Op::Div,
+            Op::Rem,
+        ];
+        ops.iter().copied().find(|op| rest.starts_with(op.symbol()))
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..self.end]
+    }
+
+    /// Returns the error pointing at the next character
+    fn error(&self, message: &'static str) -> ParseError {
+        let len = self.rest().chars().next().map_or(0, char::len_utf8);
+        ParseError::new(message, self.pos..self.pos + len)
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+use super::{bidi, join_list, plural, Context, Locale, PluralCategory, Unit};
+
+/// Hebrew language
+///
+/// Single periods are expressed with the noun alone ("לפני שעה"), and so are
+/// the dual ones for the units that have a dual form ("לפני יומיים").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hebrew {
+    isolate: bool,
+}
+
+impl Hebrew {
+    /// Sets whether the numbers and the whole phrase are wrapped into Unicode bidi
+    /// isolates, so the text can be safely embedded into left-to-right text
+    #[must_use]
+    pub const fn isolated(self, isolate: bool) -> Self {
+        Self { isolate }
+    }
+
+    fn phrase(self, text: String) -> String {
+        if self.isolate {
+            bidi::rtl(text)
+        } else {
+            text
+        }
+    }
+}
+
+impl Locale for Hebrew {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::hebrew(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, two, other) = match unit {
+            Unit::Nanos => return "ננו שנ׳".into(),
+            Unit::Micros => return "מיקרו שנ׳".into(),
+            Unit::Millis => return "מילי שנ׳".into(),
+            Unit::Seconds => ("שנייה", "שניות", "שניות"),
+            Unit::Minutes => ("דקה", "דקות", "דקות"),
+            Unit::Hours => ("שעה", "שעתיים", "שעות"),
+            Unit::Days => ("יום", "יומיים", "ימים"),
+            Unit::Weeks => ("שבוע", "שבועיים", "שבועות"),
+            Unit::Months => ("חודש", "חודשיים", "חודשים"),
+            Unit::Years => ("שנה", "שנתיים", "שנים"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number(&self, count: i64) -> String {
+        let number = self.number_format().integer(count);
+        if self.isolate {
+            bidi::ltr(number)
+        } else {
+            number
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        let plural = self.plural(count);
+        match plural {
+            PluralCategory::One if unit > Unit::Millis => {
+                self.unit(unit, plural, context).into_owned()
+            }
+            PluralCategory::Two if unit > Unit::Minutes => {
+                self.unit(unit, plural, context).into_owned()
+            }
+            _ => format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, plural, context)
+            ),
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "עכשיו".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "נצח".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " ו")
+    }
+
+    fn past(&self, text: String) -> String {
+        self.phrase(format!("לפני {}", text))
+    }
+
+    fn future(&self, text: String) -> String {
+        self.phrase(format!("בעוד {}", text))
+    }
+
+    fn present(&self, text: String) -> String {
+        self.phrase(text)
+    }
+}
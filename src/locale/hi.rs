@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+use super::{join_list, number, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Hindi language
+///
+/// The last period is followed by the tense postposition and takes the oblique form
+/// ("एक घंटे पहले", "एक महीने में").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hindi {
+    native: bool,
+}
+
+impl Hindi {
+    /// Sets whether the numbers are written with Devanagari digits ("३ घंटे पहले")
+    #[must_use]
+    pub const fn native_digits(self, native: bool) -> Self {
+        Self { native }
+    }
+}
+
+impl Locale for Hindi {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::zero_one(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let oblique = context.last && context.tense != Tense::Present;
+        let (one, other) = match unit {
+            Unit::Nanos => return "नैनोसेकंड".into(),
+            Unit::Micros => return "माइक्रोसेकंड".into(),
+            Unit::Millis => return "मिलीसेकंड".into(),
+            Unit::Seconds => ("सेकंड", "सेकंड"),
+            Unit::Minutes => ("मिनट", "मिनट"),
+            Unit::Hours if oblique => ("घंटे", "घंटे"),
+            Unit::Hours => ("घंटा", "घंटे"),
+            Unit::Days => ("दिन", "दिन"),
+            Unit::Weeks if oblique => ("हफ़्ते", "हफ़्ते"),
+            Unit::Weeks => ("हफ़्ता", "हफ़्ते"),
+            Unit::Months if oblique => ("महीने", "महीने"),
+            Unit::Months => ("महीना", "महीने"),
+            Unit::Years => ("साल", "साल"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        let format = NumberFormat::new(Some(','), '.').with_grouping(3, 2);
+        if self.native {
+            format.with_digits(number::DEVANAGARI)
+        } else {
+            format
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("एक {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "अब".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "अनंत काल".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " और ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} पहले", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} में", text)
+    }
+}
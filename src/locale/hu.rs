@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Hungarian language
+///
+/// In the past tense the last period takes the instrumental suffix that follows
+/// the vowel harmony of the unit ("3 órával ezelőtt", "1 hónap és 2 héttel ezelőtt").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hungarian;
+
+impl Locale for Hungarian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "nanoszekundum",
+            Unit::Micros => "mikroszekundum",
+            Unit::Millis => "milliszekundum",
+            Unit::Seconds => "másodperc",
+            Unit::Minutes => "perc",
+            Unit::Hours => "óra",
+            Unit::Days => "nap",
+            Unit::Weeks => "hét",
+            Unit::Months => "hónap",
+            Unit::Years => "év",
+        };
+
+        if context.tense == Tense::Past && context.last {
+            instrumental(text).into()
+        } else {
+            text.into()
+        }
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',').with_min_grouping(2)
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("egy {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "most".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "örökkévalóság".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " és ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} ezelőtt", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} múlva", text)
+    }
+}
+
+/// Attaches instrumental suffix -val/-vel to the `word`
+///
+/// The suffix vowel follows the last vowel of the word, final short "a" and "e"
+/// become long, and "v" assimilates to the final consonant ("perc" -> "perccel").
+fn instrumental(word: &str) -> String {
+    let back = word
+        .chars()
+        .rev()
+        .find(|c| "aáoóuúeéiíöőüű".contains(*c))
+        .is_some_and(|c| "aáoóuú".contains(c));
+    let suffix = if back { "al" } else { "el" };
+
+    let mut text = word.to_string();
+    match text.pop() {
+        Some('a') => text.push_str("áv"),
+        Some('e') => text.push_str("év"),
+        Some(c) if "áéiíoóöőuúüű".contains(c) => {
+            text.push(c);
+            text.push('v');
+        }
+        Some(c) => {
+            text.push(c);
+            text.push(c);
+        }
+        None => {}
+    }
+    text.push_str(suffix);
+    text
+}
@@ -0,0 +1,231 @@
+use std::ops::Range;
+
+use fixed_decimal::Decimal;
+use icu_experimental::duration::options::{BaseStyle, DurationFormatterOptions};
+use icu_experimental::duration::{Duration, DurationFormatter, ValidatedDurationFormatterOptions};
+use icu_experimental::relativetime::options::Numeric;
+use icu_experimental::relativetime::{
+    RelativeTimeFormatter, RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+};
+use icu_provider::DataError;
+
+use super::{English, Locale, Unit};
+use crate::{Accuracy, ParseError, Tense};
+
+type Constructor = fn(
+    RelativeTimeFormatterPreferences,
+    RelativeTimeFormatterOptions,
+) -> Result<RelativeTimeFormatter, DataError>;
+
+/// Stands for the text in the tenses of the fallback locale
+const PLACEHOLDER: &str = "{0}";
+
+/// Relative time formatters of the units from seconds to years
+const RELATIVE: [Constructor; 7] = [
+    RelativeTimeFormatter::try_new_long_second,
+    RelativeTimeFormatter::try_new_long_minute,
+    RelativeTimeFormatter::try_new_long_hour,
+    RelativeTimeFormatter::try_new_long_day,
+    RelativeTimeFormatter::try_new_long_week,
+    RelativeTimeFormatter::try_new_long_month,
+    RelativeTimeFormatter::try_new_long_year,
+];
+
+/// Texts produced by the ICU4X relative time and duration formatters
+///
+/// `HumanTime` still decides which units and counts to express, the formatters
+/// spell them out with the compiled-in CLDR data. A single period in the past or
+/// future tense goes through the relative time formatter, where the rough accuracy
+/// permits the words such as "yesterday" or "next month". Several periods go through
+/// the duration formatter; ICU4X cannot put them into a tense, so the list takes the
+/// place of the largest period in its relative time text, or, when the relative time
+/// text does not contain the period as is, the list is put into the tense the way the
+/// fallback locale does it, English unless `with_fallback()` says otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::IcuFormatter;
+/// use chrono_humanize::{Accuracy, HumanTime, Tense};
+///
+/// let french = IcuFormatter::try_new("fr").unwrap();
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+/// assert_eq!("il y a 3 heures", ht.to_text_icu(&french, Accuracy::Rough, Tense::Past));
+///
+/// let english = IcuFormatter::try_new("en").unwrap();
+///
+/// let ht = HumanTime::from(Duration::days(45));
+/// assert_eq!(
+///     "in 1 month, 2 weeks, 1 day",
+///     ht.to_text_icu(&english, Accuracy::Precise, Tense::Future)
+/// );
+/// ```
+#[derive(Debug)]
+pub struct IcuFormatter {
+    duration: DurationFormatter,
+    rough: Vec<RelativeTimeFormatter>,
+    precise: Vec<RelativeTimeFormatter>,
+    eternity: String,
+    /// Past tense of the fallback locale, with "{0}" for the text
+    past: String,
+    /// Future tense of the fallback locale, with "{0}" for the text
+    future: String,
+}
+
+impl IcuFormatter {
+    /// Creates formatter for the BCP-47 language `tag`
+    ///
+    /// The languages missing from the compiled-in data fall back to the less specific
+    /// ones and, eventually, to the root locale.
+    pub fn try_new(tag: &str) -> Result<Self, ParseError> {
+        let locale = tag
+            .parse::<icu_locale_core::Locale>()
+            .map_err(|_| ParseError::new("invalid language tag", 0..tag.len()))?;
+        let unavailable = |_| ParseError::new("no ICU4X data for the language", 0..tag.len());
+
+        let mut options = DurationFormatterOptions::default();
+        options.base = BaseStyle::Long;
+        let options = ValidatedDurationFormatterOptions::validate(options)
+            .expect("long style is always valid");
+        let duration =
+            DurationFormatter::try_new((&locale).into(), options).map_err(unavailable)?;
+
+        let relative = |numeric| {
+            let mut options = RelativeTimeFormatterOptions::default();
+            options.numeric = numeric;
+            RELATIVE
+                .iter()
+                .map(|constructor| constructor((&locale).into(), options))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(unavailable)
+        };
+
+        Ok(Self {
+            duration,
+            rough: relative(Numeric::Auto)?,
+            precise: relative(Numeric::Always)?,
+            eternity: English.eternity().into_owned(),
+            past: English.past(PLACEHOLDER.into()),
+            future: English.future(PLACEHOLDER.into()),
+        })
+    }
+
+    /// Takes the texts ICU4X has no data for, i.e. the eternity and the tenses of the
+    /// lists missing from the relative time texts, from the `locale` instead of English
+    #[must_use]
+    pub fn with_fallback(self, locale: &dyn Locale) -> Self {
+        Self {
+            eternity: locale.eternity().into_owned(),
+            past: locale.past(PLACEHOLDER.into()),
+            future: locale.future(PLACEHOLDER.into()),
+            ..self
+        }
+    }
+
+    /// Returns the text for the durations too long to express, taken from the fallback
+    /// locale
+    #[must_use]
+    pub fn eternity(&self) -> &str {
+        &self.eternity
+    }
+
+    /// Returns the text for the present moment, e.g. "now"
+    pub(crate) fn now(&self) -> String {
+        self.rough[0].format(Decimal::from(0)).to_string()
+    }
+
+    /// Returns the text of the `periods`, the largest one first, in the `tense`
+    pub(crate) fn format(
+        &self,
+        periods: &[(Unit, i64)],
+        accuracy: Accuracy,
+        tense: Tense,
+    ) -> String {
+        let text = self.duration(periods);
+        if text.is_empty() {
+            return self.now();
+        }
+
+        let sign = match tense {
+            Tense::Past => -1,
+            Tense::Present => return text,
+            Tense::Future => 1,
+        };
+        let formatters = match accuracy {
+            Accuracy::Rough => &self.rough,
+            Accuracy::Precise => &self.precise,
+        };
+
+        match *periods {
+            [(unit, count)] if unit >= Unit::Seconds => {
+                Self::relative(formatters, unit, sign * count)
+            }
+            [(unit, count), ..] => {
+                // Sub-second periods have no relative time text of their own
+                let (unit, count) = if unit < Unit::Seconds {
+                    (Unit::Seconds, 1)
+                } else {
+                    (unit, count)
+                };
+                let mut relative = Self::relative(formatters, unit, sign * count);
+                let largest = self.duration(&[(unit, count)]);
+                match find_period(&relative, &largest) {
+                    Some(range) => {
+                        relative.replace_range(range, &text);
+                        relative
+                    }
+                    None if sign < 0 => self.past.replace(PLACEHOLDER, &text),
+                    None => self.future.replace(PLACEHOLDER, &text),
+                }
+            }
+            [] => text,
+        }
+    }
+
+    fn relative(formatters: &[RelativeTimeFormatter], unit: Unit, count: i64) -> String {
+        let idx = unit as usize - Unit::Seconds as usize;
+        formatters[idx].format(Decimal::from(count)).to_string()
+    }
+
+    fn duration(&self, periods: &[(Unit, i64)]) -> String {
+        let mut duration = Duration::new();
+        for &(unit, count) in periods {
+            let count = count.unsigned_abs();
+            match unit {
+                Unit::Nanos => duration.nanoseconds = count,
+                Unit::Micros => duration.microseconds = count,
+                Unit::Millis => duration.milliseconds = count,
+                Unit::Seconds => duration.seconds = count,
+                Unit::Minutes => duration.minutes = count,
+                Unit::Hours => duration.hours = count,
+                Unit::Days => duration.days = count,
+                Unit::Weeks => duration.weeks = count,
+                Unit::Months => duration.months = count,
+                Unit::Years => duration.years = count,
+            }
+        }
+        self.duration.format(&duration).to_string()
+    }
+}
+
+/// Returns where the `period` text is in the `text`, the kinds of spaces
+/// the formatters put between the number and the unit do not matter
+fn find_period(text: &str, period: &str) -> Option<Range<usize>> {
+    text.char_indices().find_map(|(start, _)| {
+        let mut chars = text[start..].char_indices();
+        for expected in period.chars() {
+            let (_, c) = chars.next()?;
+            if c != expected && !(c.is_whitespace() && expected.is_whitespace()) {
+                return None;
+            }
+        }
+        // The period must not be a part of a longer word, "Tage" of "Tagen"
+        match chars.next() {
+            Some((_, c)) if c.is_alphanumeric() => None,
+            Some((end, _)) => Some(start..start + end),
+            None => Some(start..text.len()),
+        }
+    })
+}
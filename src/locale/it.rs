@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Italian language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Italian;
+
+impl Locale for Italian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("secondo", "secondi"),
+            Unit::Minutes => ("minuto", "minuti"),
+            Unit::Hours => ("ora", "ore"),
+            Unit::Days => ("giorno", "giorni"),
+            Unit::Weeks => ("settimana", "settimane"),
+            Unit::Months => ("mese", "mesi"),
+            Unit::Years => ("anno", "anni"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',').with_min_grouping(2)
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            let article = match unit {
+                Unit::Hours => "un'",
+                Unit::Weeks => "una ",
+                _ => "un ",
+            };
+            format!(
+                "{}{}",
+                article,
+                self.unit(unit, PluralCategory::One, context)
+            )
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "adesso".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eternità".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " e ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} fa", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("tra {}", text)
+    }
+}
@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use super::{plural, Context, Locale, PluralCategory, Unit};
+
+/// Japanese language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Japanese;
+
+impl Locale for Japanese {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "ナノ秒",
+            Unit::Micros => "マイクロ秒",
+            Unit::Millis => "ミリ秒",
+            Unit::Seconds => "秒",
+            Unit::Minutes => "分",
+            Unit::Hours => "時間",
+            Unit::Days => "日",
+            Unit::Weeks => "週間",
+            Unit::Months => "か月",
+            Unit::Years => "年",
+        };
+        text.into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        format!(
+            "{}{}",
+            self.number(count),
+            self.unit(unit, self.plural(count), context)
+        )
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "今".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "永遠".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        parts.concat()
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{}前", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{}後", text)
+    }
+}
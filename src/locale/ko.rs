@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use super::{plural, Context, Locale, PluralCategory, Unit};
+
+/// Korean language
+///
+/// The number and the unit are written together ("3시간"), while the periods
+/// and the tense marker are separated by spaces ("1개월 2주 전").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Korean;
+
+impl Locale for Korean {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "나노초",
+            Unit::Micros => "마이크로초",
+            Unit::Millis => "밀리초",
+            Unit::Seconds => "초",
+            Unit::Minutes => "분",
+            Unit::Hours => "시간",
+            Unit::Days => "일",
+            Unit::Weeks => "주",
+            Unit::Months => "개월",
+            Unit::Years => "년",
+        };
+        text.into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        format!(
+            "{}{}",
+            self.number(count),
+            self.unit(unit, self.plural(count), context)
+        )
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "지금".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "영원".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        parts.join(" ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} 전", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} 후", text)
+    }
+}
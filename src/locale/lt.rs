@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Lithuanian language
+///
+/// Periods are put into the accusative case in the past tense ("prieš 3 valandas")
+/// and into the genitive case in the future tense ("po 3 valandų").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lithuanian;
+
+impl Locale for Lithuanian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::lithuanian(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let (one, few, other) = match (unit, context.tense) {
+            (Unit::Nanos, _) => return "ns".into(),
+            (Unit::Micros, _) => return "µs".into(),
+            (Unit::Millis, _) => return "ms".into(),
+            (Unit::Seconds, Tense::Past) => ("sekundę", "sekundes", "sekundžių"),
+            (Unit::Seconds, Tense::Future) => ("sekundės", "sekundžių", "sekundžių"),
+            (Unit::Seconds, Tense::Present) => ("sekundė", "sekundės", "sekundžių"),
+            (Unit::Minutes, Tense::Past) => ("minutę", "minutes", "minučių"),
+            (Unit::Minutes, Tense::Future) => ("minutės", "minučių", "minučių"),
+            (Unit::Minutes, Tense::Present) => ("minutė", "minutės", "minučių"),
+            (Unit::Hours, Tense::Past) => ("valandą", "valandas", "valandų"),
+            (Unit::Hours, Tense::Future) => ("valandos", "valandų", "valandų"),
+            (Unit::Hours, Tense::Present) => ("valanda", "valandos", "valandų"),
+            (Unit::Days, Tense::Past) => ("dieną", "dienas", "dienų"),
+            (Unit::Days, Tense::Future) => ("dienos", "dienų", "dienų"),
+            (Unit::Days, Tense::Present) => ("diena", "dienos", "dienų"),
+            (Unit::Weeks, Tense::Past) => ("savaitę", "savaites", "savaičių"),
+            (Unit::Weeks, Tense::Future) => ("savaitės", "savaičių", "savaičių"),
+            (Unit::Weeks, Tense::Present) => ("savaitė", "savaitės", "savaičių"),
+            (Unit::Months, Tense::Past) => ("mėnesį", "mėnesius", "mėnesių"),
+            (Unit::Months, Tense::Future) => ("mėnesio", "mėnesių", "mėnesių"),
+            (Unit::Months, Tense::Present) => ("mėnuo", "mėnesiai", "mėnesių"),
+            (Unit::Years, Tense::Past) => ("metus", "metus", "metų"),
+            (Unit::Years, Tense::Future) => ("metų", "metų", "metų"),
+            (Unit::Years, Tense::Present) => ("metai", "metai", "metų"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',')
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "dabar".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "amžinybė".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " ir ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("prieš {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("po {}", text)
+    }
+}
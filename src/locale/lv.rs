@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Latvian language
+///
+/// Both "pirms" and "pēc" take the genitive case in singular and the dative case
+/// in plural ("pirms 1 stundas", "pēc 3 stundām").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Latvian;
+
+impl Locale for Latvian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::latvian(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let oblique = context.tense != Tense::Present;
+        let (zero, one, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds if oblique => ("sekundēm", "sekundes", "sekundēm"),
+            Unit::Seconds => ("sekunžu", "sekunde", "sekundes"),
+            Unit::Minutes if oblique => ("minūtēm", "minūtes", "minūtēm"),
+            Unit::Minutes => ("minūšu", "minūte", "minūtes"),
+            Unit::Hours if oblique => ("stundām", "stundas", "stundām"),
+            Unit::Hours => ("stundu", "stunda", "stundas"),
+            Unit::Days if oblique => ("dienām", "dienas", "dienām"),
+            Unit::Days => ("dienu", "diena", "dienas"),
+            Unit::Weeks if oblique => ("nedēļām", "nedēļas", "nedēļām"),
+            Unit::Weeks => ("nedēļu", "nedēļa", "nedēļas"),
+            Unit::Months if oblique => ("mēnešiem", "mēneša", "mēnešiem"),
+            Unit::Months => ("mēnešu", "mēnesis", "mēneši"),
+            Unit::Years if oblique => ("gadiem", "gada", "gadiem"),
+            Unit::Years => ("gadu", "gads", "gadi"),
+        };
+
+        match plural {
+            PluralCategory::Zero => zero,
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',').with_min_grouping(2)
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "tagad".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "mūžība".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " un ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("pirms {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("pēc {}", text)
+    }
+}
@@ -0,0 +1,392 @@
+//! Human languages `HumanTime` can be expressed in
+//!
+//! Every text `HumanTime` produces goes through an implementation of the `Locale` trait.
+//! The locale decides how a single time period is spelled out, how several periods are
+//! joined together and how the result is put into the past or future tense.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::Duration;
+//! use chrono_humanize::locale::English;
+//! use chrono_humanize::{Accuracy, HumanTime, Tense};
+//!
+//! let ht = HumanTime::from(Duration::hours(-3));
+//!
+//! assert_eq!("3 hours ago", ht.to_text(&English, Accuracy::Rough, Tense::Past));
+//! ```
+
+use std::borrow::Cow;
+
+use crate::{Accuracy, Tense};
+
+#[cfg(feature = "locale-ar")]
+pub use self::ar::Arabic;
+#[cfg(feature = "locale-bn")]
+pub use self::bn::Bengali;
+#[cfg(feature = "cldr")]
+pub use self::cldr::CldrLocale;
+pub use self::coverage::{Coverage, Message, Status};
+#[cfg(feature = "locale-cs")]
+pub use self::cs::Czech;
+#[cfg(feature = "custom")]
+pub use self::custom::CustomLocale;
+#[cfg(feature = "locale-cy")]
+pub use self::cy::Welsh;
+#[cfg(feature = "locale-de")]
+pub use self::de::German;
+pub use self::en::English;
+#[cfg(feature = "locale-es")]
+pub use self::es::Spanish;
+#[cfg(feature = "locale-fi")]
+pub use self::fi::Finnish;
+#[cfg(feature = "fluent")]
+pub use self::fluent::FluentLocale;
+#[cfg(feature = "locale-fr")]
+pub use self::fr::French;
+#[cfg(feature = "locale-ga")]
+pub use self::ga::Irish;
+#[cfg(feature = "gettext")]
+pub use self::gettext::{Catalog, GettextLocale, PluralForms};
+#[cfg(feature = "locale-he")]
+pub use self::he::Hebrew;
+#[cfg(feature = "locale-hi")]
+pub use self::hi::Hindi;
+#[cfg(feature = "locale-hu")]
+pub use self::hu::Hungarian;
+#[cfg(feature = "icu4x")]
+pub use self::icu::IcuFormatter;
+#[cfg(feature = "locale-it")]
+pub use self::it::Italian;
+#[cfg(feature = "locale-ja")]
+pub use self::ja::Japanese;
+#[cfg(feature = "locale-ko")]
+pub use self::ko::Korean;
+#[cfg(feature = "locale-lt")]
+pub use self::lt::Lithuanian;
+#[cfg(feature = "locale-lv")]
+pub use self::lv::Latvian;
+#[cfg(feature = "locale-mr")]
+pub use self::mr::Marathi;
+#[cfg(feature = "locale-nl")]
+pub use self::nl::Dutch;
+pub use self::number::NumberFormat;
+#[cfg(feature = "locale-pl")]
+pub use self::pl::Polish;
+pub use self::pseudo::PseudoLocale;
+#[cfg(feature = "locale-pt")]
+pub use self::pt::{EuropeanPortuguese, Portuguese};
+pub use self::registry::{env_tag, fallback_chain, from_env, lookup, Registry};
+#[cfg(feature = "locale-ro")]
+pub use self::ro::Romanian;
+#[cfg(feature = "locale-ru")]
+pub use self::ru::Russian;
+pub use self::rules::{PluralOperands, PluralRules};
+#[cfg(feature = "locale-tr")]
+pub use self::tr::Turkish;
+#[cfg(feature = "locale-uk")]
+pub use self::uk::Ukrainian;
+#[cfg(feature = "locale-zh")]
+pub use self::zh::{SimplifiedChinese, TraditionalChinese};
+
+#[cfg(feature = "locale-ar")]
+mod ar;
+#[cfg(any(feature = "locale-ar", feature = "locale-he"))]
+mod bidi;
+#[cfg(feature = "locale-bn")]
+mod bn;
+#[cfg(feature = "cldr")]
+mod cldr;
+mod coverage;
+#[cfg(feature = "locale-cs")]
+mod cs;
+#[cfg(feature = "custom")]
+mod custom;
+#[cfg(feature = "locale-cy")]
+mod cy;
+#[cfg(feature = "locale-de")]
+mod de;
+mod en;
+#[cfg(feature = "locale-es")]
+mod es;
+#[cfg(feature = "locale-fi")]
+mod fi;
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "locale-ga")]
+mod ga;
+#[cfg(feature = "gettext")]
+mod gettext;
+#[cfg(feature = "locale-he")]
+mod he;
+#[cfg(feature = "locale-hi")]
+mod hi;
+#[cfg(feature = "locale-hu")]
+mod hu;
+#[cfg(feature = "icu4x")]
+mod icu;
+#[cfg(feature = "locale-it")]
+mod it;
+#[cfg(feature = "locale-ja")]
+mod ja;
+#[cfg(feature = "locale-ko")]
+mod ko;
+#[cfg(feature = "locale-lt")]
+mod lt;
+#[cfg(feature = "locale-lv")]
+mod lv;
+#[cfg(feature = "locale-mr")]
+mod mr;
+#[cfg(feature = "locale-nl")]
+mod nl;
+mod number;
+#[cfg(feature = "locale-pl")]
+mod pl;
+mod plural;
+mod pseudo;
+#[cfg(feature = "locale-pt")]
+mod pt;
+mod registry;
+#[cfg(feature = "locale-ro")]
+mod ro;
+#[cfg(feature = "locale-ru")]
+mod ru;
+mod rules;
+#[cfg(feature = "locale-tr")]
+mod tr;
+#[cfg(feature = "locale-uk")]
+mod uk;
+#[cfg(feature = "locale-zh")]
+mod zh;
+
+/// Plural category of a count, as defined by Unicode CLDR
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the CLDR name of the category, e.g. "few"
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Unit of time a single period is expressed in
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Unit {
+    Nanos,
+    Micros,
+    Millis,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Unit {
+    /// Returns the plural lowercase name of the unit, e.g. "hours"
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Nanos => "nanos",
+            Self::Micros => "micros",
+            Self::Millis => "millis",
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+            Self::Weeks => "weeks",
+            Self::Months => "months",
+            Self::Years => "years",
+        }
+    }
+}
+
+/// Grammatical context a single time period is rendered in
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Context {
+    /// The accuracy of the whole representation
+    pub accuracy: Accuracy,
+    /// The tense the whole representation is going to be put into
+    pub tense: Tense,
+    /// Whether this is the first period of the representation.
+    /// Languages that express the tense with a prefix may attach it to the first period only.
+    pub first: bool,
+    /// Whether this is the last period of the representation.
+    /// Languages that express the tense with a suffix attach it to the last period only.
+    pub last: bool,
+}
+
+impl Context {
+    /// Creates context of the single period for the given `accuracy` and `tense`
+    #[must_use]
+    pub fn new(accuracy: Accuracy, tense: Tense) -> Self {
+        Self {
+            accuracy,
+            tense,
+            first: true,
+            last: true,
+        }
+    }
+}
+
+/// Durations, in seconds, the rough representation switches to the longer units after
+///
+/// A duration longer than `minute` seconds is "a minute", longer than `minutes`
+/// seconds is counted in minutes and so on. Durations of up to `now` seconds are "now".
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Thresholds {
+    pub now: i64,
+    pub minute: i64,
+    pub minutes: i64,
+    pub hour: i64,
+    pub hours: i64,
+    pub day: i64,
+    pub days: i64,
+    pub week: i64,
+    pub weeks: i64,
+    pub month: i64,
+    pub months: i64,
+    pub year: i64,
+    pub years: i64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        const MINUTE: i64 = 60;
+        const HOUR: i64 = MINUTE * 60;
+        const DAY: i64 = HOUR * 24;
+
+        Self {
+            now: 10,
+            minute: 45,
+            minutes: 90,
+            hour: 45 * MINUTE,
+            hours: 90 * MINUTE,
+            day: 22 * HOUR,
+            days: 36 * HOUR,
+            week: 6 * DAY + 12 * HOUR,
+            weeks: 10 * DAY + 12 * HOUR,
+            month: 29 * DAY,
+            months: 45 * DAY,
+            year: 345 * DAY,
+            years: 547 * DAY,
+        }
+    }
+}
+
+/// How the rough representation turns the duration into the count of its unit
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Rounding {
+    /// Whole units only, 2 hours and 40 minutes are "2 hours"
+    #[default]
+    Down,
+    /// The nearest whole number of units, 2 hours and 40 minutes are "3 hours", the way
+    /// moment.js and dayjs count. The months and years are of the average Gregorian
+    /// length then, rather than of 30 and 365 days.
+    Nearest,
+}
+
+/// Human language `HumanTime` is expressed in
+pub trait Locale {
+    /// Returns the plural category `count` belongs to
+    fn plural(&self, count: i64) -> PluralCategory;
+
+    /// Returns the name of the `unit` in the given `plural` form, e.g. "minutes"
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str>;
+
+    /// Returns how the locale writes the numbers
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::default()
+    }
+
+    /// Returns the text for the `count` itself, e.g. "1,234" or "३"
+    fn number(&self, count: i64) -> String {
+        self.number_format().integer(count)
+    }
+
+    /// Returns the text for `count` of `unit`s, e.g. "3 minutes" or "a minute"
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        let plural = self.plural(count);
+        format!(
+            "{} {}",
+            self.number(count),
+            self.unit(unit, plural, context)
+        )
+    }
+
+    /// Returns the text for the present moment
+    fn now(&self) -> Cow<'_, str>;
+
+    /// Returns the text for the time period too long to be expressed
+    fn eternity(&self) -> Cow<'_, str>;
+
+    /// Joins the texts of several time periods into one list
+    fn join(&self, parts: Vec<String>) -> String;
+
+    /// Puts the text into the past tense, e.g. "3 minutes ago"
+    fn past(&self, text: String) -> String;
+
+    /// Puts the text into the future tense, e.g. "in 3 minutes"
+    fn future(&self, text: String) -> String;
+
+    /// Puts the text into the present tense
+    fn present(&self, text: String) -> String {
+        text
+    }
+
+    /// Returns the durations the rough representation switches between the units at
+    fn thresholds(&self) -> Thresholds {
+        Thresholds::default()
+    }
+
+    /// Returns how the rough representation rounds the counts of the units
+    fn rounding(&self) -> Rounding {
+        Rounding::default()
+    }
+}
+
+/// Joins `parts` with `separator`, using `last` in front of the final part
+pub(crate) fn join_list(parts: Vec<String>, separator: &str, last: &str) -> String {
+    let mut parts = parts.into_iter();
+    let mut text = parts.next().unwrap_or_default();
+    let mut pending = parts.next();
+
+    for part in parts {
+        if let Some(prev) = pending.replace(part) {
+            text.push_str(separator);
+            text.push_str(&prev);
+        }
+    }
+
+    if let Some(prev) = pending {
+        text.push_str(last);
+        text.push_str(&prev);
+    }
+
+    text
+}
@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use super::{join_list, number, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Marathi language
+///
+/// The tense postpositions are written together with the last period,
+/// which takes the oblique form ("३ तासांपूर्वी", "१ तासामध्ये").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Marathi {
+    native: bool,
+}
+
+impl Marathi {
+    /// Sets whether the numbers are written with Devanagari digits
+    #[must_use]
+    pub const fn native_digits(self, native: bool) -> Self {
+        Self { native }
+    }
+}
+
+impl Locale for Marathi {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let oblique = context.last && context.tense != Tense::Present;
+        let (one, other) = match unit {
+            Unit::Nanos if oblique => ("नॅनोसेकंदा", "नॅनोसेकंदां"),
+            Unit::Nanos => ("नॅनोसेकंद", "नॅनोसेकंद"),
+            Unit::Micros if oblique => ("मायक्रोसेकंदा", "मायक्रोसेकंदां"),
+            Unit::Micros => ("मायक्रोसेकंद", "मायक्रोसेकंद"),
+            Unit::Millis if oblique => ("मिलिसेकंदा", "मिलिसेकंदां"),
+            Unit::Millis => ("मिलिसेकंद", "मिलिसेकंद"),
+            Unit::Seconds if oblique => ("सेकंदा", "सेकंदां"),
+            Unit::Seconds => ("सेकंद", "सेकंद"),
+            Unit::Minutes if oblique => ("मिनिटा", "मिनिटां"),
+            Unit::Minutes => ("मिनिट", "मिनिटे"),
+            Unit::Hours if oblique => ("तासा", "तासां"),
+            Unit::Hours => ("तास", "तास"),
+            Unit::Days if oblique => ("दिवसा", "दिवसां"),
+            Unit::Days => ("दिवस", "दिवस"),
+            Unit::Weeks if oblique => ("आठवड्या", "आठवड्यां"),
+            Unit::Weeks => ("आठवडा", "आठवडे"),
+            Unit::Months if oblique => ("महिन्या", "महिन्यां"),
+            Unit::Months => ("महिना", "महिने"),
+            Unit::Years if oblique => ("वर्षा", "वर्षां"),
+            Unit::Years => ("वर्ष", "वर्षे"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        let format = NumberFormat::new(Some(','), '.').with_grouping(3, 2);
+        if self.native {
+            format.with_digits(number::DEVANAGARI)
+        } else {
+            format
+        }
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("एक {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "आता".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "अनंतकाळ".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " आणि ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{}पूर्वी", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{}मध्ये", text)
+    }
+}
@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Dutch language
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dutch;
+
+impl Locale for Dutch {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("seconde", "seconden"),
+            Unit::Minutes => ("minuut", "minuten"),
+            Unit::Hours => ("uur", "uur"),
+            Unit::Days => ("dag", "dagen"),
+            Unit::Weeks => ("week", "weken"),
+            Unit::Months => ("maand", "maanden"),
+            Unit::Years => ("jaar", "jaar"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("een {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "nu".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eeuwigheid".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " en ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} geleden", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("over {}", text)
+    }
+}
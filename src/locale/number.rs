@@ -0,0 +1,166 @@
+/// How a locale writes the numbers
+///
+/// The digits are grouped from the right, the first group has `grouping` digits and
+/// every other group `secondary_grouping` digits, e.g. "12,34,567" in India. Numbers
+/// with fewer than `grouping + min_grouping` digits in the integer part are not grouped,
+/// so that Spanish has "1234" next to "12.345".
+///
+/// # Examples
+///
+/// ```
+/// use chrono_humanize::locale::NumberFormat;
+///
+/// let english = NumberFormat::default();
+/// assert_eq!("1,234,567", english.integer(1_234_567));
+/// assert_eq!("1.5", english.decimal(15, 1));
+///
+/// let french = NumberFormat::new(Some('\u{202f}'), ',');
+/// assert_eq!("1\u{202f}234", french.integer(1234));
+/// assert_eq!("0,25", french.decimal(25, 2));
+///
+/// let hindi = NumberFormat::new(Some(','), '.').with_grouping(3, 2).with_digits('०');
+/// assert_eq!("१२,३४,५६७", hindi.integer(1_234_567));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+#[non_exhaustive]
+pub struct NumberFormat {
+    /// The digit zero of the numbering system, the other digits follow it in Unicode,
+    /// e.g. '0', '٠' (Arabic-Indic), '०' (Devanagari) or '๐' (Thai)
+    pub zero: char,
+    /// The separator of the digit groups, no grouping if `None`
+    pub group_separator: Option<char>,
+    /// The separator of the fraction
+    pub decimal_separator: char,
+    /// The number of digits in the rightmost group
+    pub grouping: u8,
+    /// The number of digits in the other groups
+    pub secondary_grouping: u8,
+    /// The smallest number of digits in the leftmost group for the number to be grouped
+    pub min_grouping: u8,
+}
+
+impl NumberFormat {
+    /// Creates format with the given separators, Latin digits and groups of three digits
+    #[must_use]
+    pub const fn new(group_separator: Option<char>, decimal_separator: char) -> Self {
+        Self {
+            zero: '0',
+            group_separator,
+            decimal_separator,
+            grouping: 3,
+            secondary_grouping: 3,
+            min_grouping: 1,
+        }
+    }
+
+    /// Returns the same format with the digits starting at `zero`
+    #[must_use]
+    pub const fn with_digits(self, zero: char) -> Self {
+        Self { zero, ..self }
+    }
+
+    /// Returns the same format with the given sizes of the digit groups
+    #[must_use]
+    pub const fn with_grouping(self, grouping: u8, secondary_grouping: u8) -> Self {
+        Self {
+            grouping,
+            secondary_grouping,
+            ..self
+        }
+    }
+
+    /// Returns the same format that leaves the numbers with fewer than
+    /// `grouping + min_grouping` digits ungrouped
+    #[must_use]
+    pub const fn with_min_grouping(self, min_grouping: u8) -> Self {
+        Self {
+            min_grouping,
+            ..self
+        }
+    }
+
+    /// Writes the integer `n`
+    #[must_use]
+    pub fn integer(&self, n: i64) -> String {
+        self.decimal(n, 0)
+    }
+
+    /// Writes the decimal number `n / 10^scale` with `scale` digits in the fraction,
+    /// e.g. `decimal(15, 1)` is "1.5"
+    #[must_use]
+    pub fn decimal(&self, n: i64, scale: u32) -> String {
+        let scale = scale as usize;
+        let digits = format!("{:0>width$}", n.unsigned_abs(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+
+        let mut text = String::new();
+        if n < 0 {
+            text.push('-');
+        }
+        text.push_str(&self.grouped(integer));
+        if !fraction.is_empty() {
+            text.push(self.decimal_separator);
+            text.push_str(fraction);
+        }
+
+        text.chars().map(|c| self.digit(c)).collect()
+    }
+
+    fn grouped(&self, integer: &str) -> String {
+        let separator = match self.group_separator {
+            Some(separator)
+                if self.grouping > 0
+                    && integer.len()
+                        >= usize::from(self.grouping) + usize::from(self.min_grouping) =>
+            {
+                separator
+            }
+            _ => return integer.to_string(),
+        };
+
+        let mut groups = vec![];
+        let mut end = integer.len();
+        let mut size = usize::from(self.grouping);
+        while end > size {
+            groups.push(&integer[end - size..end]);
+            end -= size;
+            size = usize::from(self.secondary_grouping.max(1));
+        }
+        groups.push(&integer[..end]);
+
+        let mut text = String::new();
+        for (idx, group) in groups.iter().rev().enumerate() {
+            if idx > 0 {
+                text.push(separator);
+            }
+            text.push_str(group);
+        }
+        text
+    }
+
+    fn digit(&self, c: char) -> char {
+        match c.to_digit(10) {
+            Some(d) if c.is_ascii_digit() => std::char::from_u32(self.zero as u32 + d).unwrap_or(c),
+            _ => c,
+        }
+    }
+}
+
+impl Default for NumberFormat {
+    /// English format, "1,234.5"
+    fn default() -> Self {
+        Self::new(Some(','), '.')
+    }
+}
+
+/// DEVANAGARI DIGIT ZERO
+#[cfg(any(feature = "locale-hi", feature = "locale-mr"))]
+pub(crate) const DEVANAGARI: char = '\u{0966}';
+/// BENGALI DIGIT ZERO
+#[cfg(feature = "locale-bn")]
+pub(crate) const BENGALI: char = '\u{09E6}';
+/// ARABIC-INDIC DIGIT ZERO
+#[cfg(feature = "locale-ar")]
+pub(crate) const ARABIC_INDIC: char = '\u{0660}';
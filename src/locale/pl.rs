@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Polish language
+///
+/// Time periods are put into the accusative case in the past ("minutę temu")
+/// and the future ("za minutę") tenses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Polish;
+
+impl Locale for Polish {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::polish(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let accusative = context.tense != Tense::Present;
+        let (one, few, many) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds if accusative => ("sekundę", "sekundy", "sekund"),
+            Unit::Seconds => ("sekunda", "sekundy", "sekund"),
+            Unit::Minutes if accusative => ("minutę", "minuty", "minut"),
+            Unit::Minutes => ("minuta", "minuty", "minut"),
+            Unit::Hours if accusative => ("godzinę", "godziny", "godzin"),
+            Unit::Hours => ("godzina", "godziny", "godzin"),
+            Unit::Days => ("dzień", "dni", "dni"),
+            Unit::Weeks => ("tydzień", "tygodnie", "tygodni"),
+            Unit::Months => ("miesiąc", "miesiące", "miesięcy"),
+            Unit::Years => ("rok", "lata", "lat"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => many,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',').with_min_grouping(2)
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Seconds {
+            self.unit(unit, PluralCategory::One, context).into_owned()
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "teraz".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "wieczność".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " i ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} temu", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("za {}", text)
+    }
+}
@@ -0,0 +1,187 @@
+//! Plural rules of the built-in locales, as defined by Unicode CLDR
+//!
+//! Counts of time periods are always whole numbers, so only the integer
+//! part of each rule is implemented here.
+
+// Locales may be left out by the cargo features, and their rules with them
+#![cfg_attr(not(feature = "all-locales"), allow(dead_code))]
+
+use super::PluralCategory;
+
+/// other: everything (Chinese, Japanese, Korean, ...)
+pub(crate) fn other(_n: i64) -> PluralCategory {
+    PluralCategory::Other
+}
+
+/// one: n = 1 (English, German, Dutch, ...)
+pub(crate) fn one_other(n: i64) -> PluralCategory {
+    if n.unsigned_abs() == 1 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// Russian and Ukrainian
+///
+/// one: n % 10 = 1 and n % 100 != 11
+/// few: n % 10 = 2..4 and n % 100 != 12..14
+/// many: everything else
+pub(crate) fn east_slavic(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n % 10, n % 100) {
+        (1, m) if m != 11 => PluralCategory::One,
+        (2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+        _ => PluralCategory::Many,
+    }
+}
+
+/// Polish
+///
+/// one: n = 1
+/// few: n % 10 = 2..4 and n % 100 != 12..14
+/// many: everything else
+pub(crate) fn polish(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n, n % 10, n % 100) {
+        (1, _, _) => PluralCategory::One,
+        (_, 2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+        _ => PluralCategory::Many,
+    }
+}
+
+/// Czech and Slovak
+///
+/// one: n = 1
+/// few: n = 2..4
+/// other: everything else (many is reserved for fractions)
+pub(crate) fn czech(n: i64) -> PluralCategory {
+    match n.unsigned_abs() {
+        1 => PluralCategory::One,
+        2..=4 => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Arabic
+///
+/// zero: n = 0
+/// one: n = 1
+/// two: n = 2
+/// few: n % 100 = 3..10
+/// many: n % 100 = 11..99
+/// other: everything else
+pub(crate) fn arabic(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n, n % 100) {
+        (0, _) => PluralCategory::Zero,
+        (1, _) => PluralCategory::One,
+        (2, _) => PluralCategory::Two,
+        (_, 3..=10) => PluralCategory::Few,
+        (_, 11..=99) => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Hebrew
+///
+/// one: n = 1
+/// two: n = 2
+/// other: everything else
+pub(crate) fn hebrew(n: i64) -> PluralCategory {
+    match n.unsigned_abs() {
+        1 => PluralCategory::One,
+        2 => PluralCategory::Two,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// French, Brazilian Portuguese, Hindi and Bengali
+///
+/// one: n = 0, 1
+/// other: everything else (French many is reserved for millions)
+pub(crate) fn zero_one(n: i64) -> PluralCategory {
+    match n.unsigned_abs() {
+        0 | 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Romanian
+///
+/// one: n = 1
+/// few: v != 0 or n = 0 or n != 1 and n % 100 = 1..19
+/// other: everything else
+pub(crate) fn romanian(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n, n % 100) {
+        (1, _) => PluralCategory::One,
+        (0, _) | (_, 1..=19) => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Welsh
+///
+/// zero: n = 0
+/// one: n = 1
+/// two: n = 2
+/// few: n = 3
+/// many: n = 6
+/// other: everything else
+pub(crate) fn welsh(n: i64) -> PluralCategory {
+    match n.unsigned_abs() {
+        0 => PluralCategory::Zero,
+        1 => PluralCategory::One,
+        2 => PluralCategory::Two,
+        3 => PluralCategory::Few,
+        6 => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Irish
+///
+/// one: n = 1
+/// two: n = 2
+/// few: n = 3..6
+/// many: n = 7..10
+/// other: everything else
+pub(crate) fn irish(n: i64) -> PluralCategory {
+    match n.unsigned_abs() {
+        1 => PluralCategory::One,
+        2 => PluralCategory::Two,
+        3..=6 => PluralCategory::Few,
+        7..=10 => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Lithuanian
+///
+/// one: n % 10 = 1 and n % 100 != 11..19
+/// few: n % 10 = 2..9 and n % 100 != 11..19
+/// other: everything else (many is reserved for fractions)
+pub(crate) fn lithuanian(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n % 10, n % 100) {
+        (_, 11..=19) => PluralCategory::Other,
+        (1, _) => PluralCategory::One,
+        (2..=9, _) => PluralCategory::Few,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Latvian
+///
+/// zero: n % 10 = 0 or n % 100 = 11..19
+/// one: n % 10 = 1 and n % 100 != 11
+/// other: everything else
+pub(crate) fn latvian(n: i64) -> PluralCategory {
+    let n = n.unsigned_abs();
+    match (n % 10, n % 100) {
+        (0, _) | (_, 11..=19) => PluralCategory::Zero,
+        (1, _) => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
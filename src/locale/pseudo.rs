@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use super::{Context, English, Locale, NumberFormat, PluralCategory, Rounding, Thresholds, Unit};
+
+/// Pseudo-language for testing the layout of the user interface
+///
+/// The text of the `base` locale gets accented, lengthened by half and put in
+/// brackets, so that truncated, concatenated or untranslated texts stand out.
+/// The built-in registry has it under the "en-XA" and "qps-ploc" tags.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::{English, PseudoLocale};
+/// use chrono_humanize::HumanTime;
+///
+/// let ht = HumanTime::from(Duration::hours(3));
+///
+/// assert_eq!("[îñ 3 ĥôûŕš·····]", format!("{}", ht.localized(&PseudoLocale::new(English))));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PseudoLocale<L = English> {
+    base: L,
+}
+
+impl<L: Locale> PseudoLocale<L> {
+    /// Creates pseudo-locale out of the `base` one
+    #[must_use]
+    pub fn new(base: L) -> Self {
+        Self { base }
+    }
+
+    fn pseudo(text: &str) -> String {
+        let len = text.chars().count();
+        let accented: String = text.chars().map(accent).collect();
+        format!("[{}{}]", accented, "·".repeat(len / 2))
+    }
+}
+
+impl<L: Locale> Locale for PseudoLocale<L> {
+    fn plural(&self, count: i64) -> PluralCategory {
+        self.base.plural(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        self.base.unit(unit, plural, context)
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        self.base.number_format()
+    }
+
+    fn number(&self, count: i64) -> String {
+        self.base.number(count)
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        self.base.period(unit, count, context)
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        self.base.now()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        self.base.eternity()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        self.base.join(parts)
+    }
+
+    // Every text is put into one of the tenses at the very end
+    fn past(&self, text: String) -> String {
+        Self::pseudo(&self.base.past(text))
+    }
+
+    fn future(&self, text: String) -> String {
+        Self::pseudo(&self.base.future(text))
+    }
+
+    fn present(&self, text: String) -> String {
+        Self::pseudo(&self.base.present(text))
+    }
+
+    fn thresholds(&self) -> Thresholds {
+        self.base.thresholds()
+    }
+
+    fn rounding(&self) -> Rounding {
+        self.base.rounding()
+    }
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ð',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ĝ',
+        'h' => 'ĥ',
+        'i' => 'î',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ɱ',
+        'n' => 'ñ',
+        'o' => 'ô',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ŕ',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ð',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ĝ',
+        'H' => 'Ĥ',
+        'I' => 'Ĩ',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ŕ',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        _ => c,
+    }
+}
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Portuguese language as spoken in Brazil (pt-BR)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Portuguese;
+
+/// Portuguese language as spoken in Portugal (pt-PT)
+///
+/// Unlike Brazilian Portuguese zero takes the plural form ("0 segundos")
+/// and the future tense is expressed with "dentro de".
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EuropeanPortuguese;
+
+fn unit(unit: Unit, plural: PluralCategory) -> &'static str {
+    let (one, other) = match unit {
+        Unit::Nanos => return "ns",
+        Unit::Micros => return "µs",
+        Unit::Millis => return "ms",
+        Unit::Seconds => ("segundo", "segundos"),
+        Unit::Minutes => ("minuto", "minutos"),
+        Unit::Hours => ("hora", "horas"),
+        Unit::Days => ("dia", "dias"),
+        Unit::Weeks => ("semana", "semanas"),
+        Unit::Months => ("mês", "meses"),
+        Unit::Years => ("ano", "anos"),
+    };
+
+    match plural {
+        PluralCategory::One => one,
+        _ => other,
+    }
+}
+
+fn period(locale: &dyn Locale, unit: Unit, count: i64, context: Context) -> String {
+    if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+        let article = match unit {
+            Unit::Hours | Unit::Weeks => "uma",
+            _ => "um",
+        };
+        format!(
+            "{} {}",
+            article,
+            locale.unit(unit, PluralCategory::One, context)
+        )
+    } else {
+        let plural = locale.plural(count);
+        format!(
+            "{} {}",
+            locale.number(count),
+            locale.unit(unit, plural, context)
+        )
+    }
+}
+
+impl Locale for Portuguese {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::zero_one(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        self::unit(unit, plural).into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        self::period(self, unit, count, context)
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "agora".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eternidade".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " e ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("há {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("em {}", text)
+    }
+}
+
+impl Locale for EuropeanPortuguese {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        self::unit(unit, plural).into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',').with_min_grouping(2)
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        self::period(self, unit, count, context)
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "agora".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eternidade".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " e ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("há {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("dentro de {}", text)
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::{English, Locale, PseudoLocale};
+
+/// English is the last resort for every tag
+static FALLBACK: English = English;
+
+/// Collection of locales addressed by BCP-47 language tags
+///
+/// The built-in registry has every hand-written locale enabled by its `locale-*` cargo
+/// feature (all of them with the default `all-locales` feature). With the `cldr` feature
+/// it also has a `CldrLocale` for every language in the vendored CLDR data;
+/// the hand-written locales take precedence.
+/// Tags are matched case-insensitively, and POSIX locale names such as
+/// "pt_BR.UTF-8" are accepted as well. A tag that is not registered is
+/// resolved by dropping its subtags one by one ("pt-BR" -> "pt") and,
+/// when nothing matches, falls back to English.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::locale::Registry;
+/// use chrono_humanize::{Accuracy, HumanTime, Tense};
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+/// let registry = Registry::builtin();
+///
+/// # #[cfg(feature = "locale-fr")]
+/// # {
+/// let french = registry.resolve("fr-CA");
+/// assert_eq!("il y a 3 heures", ht.to_text(french, Accuracy::Rough, Tense::Past));
+/// # }
+///
+/// let klingon = registry.resolve("tlh");
+/// assert_eq!("3 hours ago", ht.to_text(klingon, Accuracy::Rough, Tense::Past));
+/// ```
+pub struct Registry {
+    locales: BTreeMap<String, Box<dyn Locale + Send + Sync>>,
+}
+
+impl Registry {
+    /// Creates empty registry, which resolves every tag to English
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            locales: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the registry of all the locales built into this crate
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<Registry> = OnceLock::new();
+        BUILTIN.get_or_init(Self::with_builtin)
+    }
+
+    fn with_builtin() -> Self {
+        let mut registry = Self::new();
+        #[cfg(feature = "cldr")]
+        for locale in super::CldrLocale::all() {
+            registry.register(locale.tag(), locale);
+        }
+        #[cfg(feature = "locale-ar")]
+        registry.register("ar", super::Arabic::default());
+        #[cfg(feature = "locale-bn")]
+        registry.register("bn", super::Bengali::default());
+        #[cfg(feature = "locale-cs")]
+        registry.register("cs", super::Czech);
+        #[cfg(feature = "locale-cy")]
+        registry.register("cy", super::Welsh);
+        #[cfg(feature = "locale-de")]
+        registry.register("de", super::German);
+        registry.register("en", English);
+        registry.register("en-XA", PseudoLocale::<English>::default());
+        #[cfg(feature = "locale-es")]
+        registry.register("es", super::Spanish);
+        #[cfg(feature = "locale-fi")]
+        registry.register("fi", super::Finnish);
+        #[cfg(feature = "locale-fr")]
+        registry.register("fr", super::French);
+        #[cfg(feature = "locale-ga")]
+        registry.register("ga", super::Irish);
+        #[cfg(feature = "locale-he")]
+        registry.register("he", super::Hebrew::default());
+        #[cfg(feature = "locale-he")]
+        registry.register("iw", super::Hebrew::default());
+        #[cfg(feature = "locale-hi")]
+        registry.register("hi", super::Hindi::default());
+        #[cfg(feature = "locale-hu")]
+        registry.register("hu", super::Hungarian);
+        #[cfg(feature = "locale-it")]
+        registry.register("it", super::Italian);
+        #[cfg(feature = "locale-ja")]
+        registry.register("ja", super::Japanese);
+        #[cfg(feature = "locale-ko")]
+        registry.register("ko", super::Korean);
+        #[cfg(feature = "locale-lt")]
+        registry.register("lt", super::Lithuanian);
+        #[cfg(feature = "locale-lv")]
+        registry.register("lv", super::Latvian);
+        #[cfg(feature = "locale-mr")]
+        registry.register("mr", super::Marathi::default());
+        #[cfg(feature = "locale-nl")]
+        registry.register("nl", super::Dutch);
+        #[cfg(feature = "locale-pl")]
+        registry.register("pl", super::Polish);
+        #[cfg(feature = "locale-pt")]
+        registry.register("pt", super::Portuguese);
+        #[cfg(feature = "locale-pt")]
+        registry.register("pt-PT", super::EuropeanPortuguese);
+        registry.register("qps-ploc", PseudoLocale::<English>::default());
+        #[cfg(feature = "locale-ro")]
+        registry.register("ro", super::Romanian);
+        #[cfg(feature = "locale-ru")]
+        registry.register("ru", super::Russian);
+        #[cfg(feature = "locale-tr")]
+        registry.register("tr", super::Turkish);
+        #[cfg(feature = "locale-uk")]
+        registry.register("uk", super::Ukrainian);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh", super::SimplifiedChinese);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh-Hans", super::SimplifiedChinese);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh-Hant", super::TraditionalChinese);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh-HK", super::TraditionalChinese);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh-MO", super::TraditionalChinese);
+        #[cfg(feature = "locale-zh")]
+        registry.register("zh-TW", super::TraditionalChinese);
+        registry
+    }
+
+    /// Registers `locale` under the given `tag`, replacing the locale registered before
+    pub fn register<L>(&mut self, tag: &str, locale: L)
+    where
+        L: Locale + Send + Sync + 'static,
+    {
+        self.locales.insert(normalize(tag), Box::new(locale));
+    }
+
+    /// Returns the locale registered under exactly this `tag`
+    #[must_use]
+    pub fn get(&self, tag: &str) -> Option<&dyn Locale> {
+        self.locales
+            .get(&normalize(tag))
+            .map(|locale| locale.as_ref() as &dyn Locale)
+    }
+
+    /// Returns the best locale for the `tag`, falling back to the less specific tags
+    /// and, eventually, to English
+    #[must_use]
+    pub fn resolve(&self, tag: &str) -> &dyn Locale {
+        fallback_chain(tag)
+            .iter()
+            .find_map(|tag| self.get(tag))
+            .unwrap_or(&FALLBACK)
+    }
+
+    /// Returns the best locale for the time formatting settings of the environment
+    #[must_use]
+    pub fn from_env(&self) -> &dyn Locale {
+        env_tag().map_or(&FALLBACK, |tag| self.resolve(&tag))
+    }
+
+    /// Returns the tags of all the registered locales
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.locales.keys().map(String::as_str)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.tags()).finish()
+    }
+}
+
+/// Returns the tags to look up for the `tag`, from the most to the least specific one
+///
+/// ```
+/// use chrono_humanize::locale::fallback_chain;
+///
+/// assert_eq!(vec!["zh-hant-tw", "zh-hant", "zh"], fallback_chain("zh-Hant-TW"));
+/// assert_eq!(vec!["pt-br", "pt"], fallback_chain("pt_BR.UTF-8"));
+/// ```
+#[must_use]
+pub fn fallback_chain(tag: &str) -> Vec<String> {
+    let tag = normalize(tag);
+    let mut chain = vec![];
+    let mut end = tag.len();
+    while end > 0 {
+        chain.push(tag[..end].to_string());
+        end = tag[..end].rfind('-').unwrap_or(0);
+    }
+    chain
+}
+
+/// Returns the language tag of the time formatting settings of the environment
+///
+/// The variables are consulted in the POSIX order: `LC_ALL`, `LC_TIME` and `LANG`.
+/// The "C" and "POSIX" locales are reported as "en".
+#[must_use]
+pub fn env_tag() -> Option<String> {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .map(|value| match normalize(&value).as_str() {
+            "c" | "posix" => "en".to_string(),
+            tag => tag.to_string(),
+        })
+}
+
+/// Turns BCP-47 tag or POSIX locale name into lowercase BCP-47 tag
+fn normalize(tag: &str) -> String {
+    let end = tag.find(['.', '@']).unwrap_or(tag.len());
+    tag[..end].trim().replace('_', "-").to_lowercase()
+}
+
+/// Returns the built-in locale that suits the `tag` best
+///
+/// ```
+/// # #[cfg(feature = "locale-de")]
+/// # {
+/// use chrono::Duration;
+/// use chrono_humanize::{locale, Accuracy, HumanTime, Tense};
+///
+/// let ht = HumanTime::from(Duration::hours(-3));
+///
+/// let german = locale::lookup("de-AT");
+///
+/// assert_eq!("vor 3 Stunden", ht.to_text(german, Accuracy::Rough, Tense::Past));
+/// # }
+/// ```
+#[must_use]
+pub fn lookup(tag: &str) -> &'static dyn Locale {
+    Registry::builtin().resolve(tag)
+}
+
+/// Returns the built-in locale that suits the environment best
+#[must_use]
+pub fn from_env() -> &'static dyn Locale {
+    Registry::builtin().from_env()
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Romanian language
+///
+/// Counts whose last two digits are 00 or 20 to 99 take the preposition "de"
+/// ("20 de minute", "100 de minute"), the others do not ("19 minute", "101 minute").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Romanian;
+
+impl Locale for Romanian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::romanian(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let (one, few, other) = match unit {
+            Unit::Nanos => return "ns".into(),
+            Unit::Micros => return "µs".into(),
+            Unit::Millis => return "ms".into(),
+            Unit::Seconds => ("secundă", "secunde", "de secunde"),
+            Unit::Minutes => ("minut", "minute", "de minute"),
+            Unit::Hours => ("oră", "ore", "de ore"),
+            Unit::Days => ("zi", "zile", "de zile"),
+            Unit::Weeks => ("săptămână", "săptămâni", "de săptămâni"),
+            Unit::Months => ("lună", "luni", "de luni"),
+            Unit::Years => ("an", "ani", "de ani"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => other,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            let article = match unit {
+                Unit::Seconds | Unit::Minutes | Unit::Years => "un",
+                _ => "o",
+            };
+            format!(
+                "{} {}",
+                article,
+                self.unit(unit, PluralCategory::One, context)
+            )
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "acum".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "eternitate".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " și ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("acum {}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("peste {}", text)
+    }
+}
@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Russian language
+///
+/// Time periods are put into the accusative case in the past ("минуту назад")
+/// and the future ("через минуту") tenses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Russian;
+
+impl Locale for Russian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::east_slavic(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let accusative = context.tense != Tense::Present;
+        let (one, few, many) = match unit {
+            Unit::Nanos => return "нс".into(),
+            Unit::Micros => return "мкс".into(),
+            Unit::Millis => return "мс".into(),
+            Unit::Seconds if accusative => ("секунду", "секунды", "секунд"),
+            Unit::Seconds => ("секунда", "секунды", "секунд"),
+            Unit::Minutes if accusative => ("минуту", "минуты", "минут"),
+            Unit::Minutes => ("минута", "минуты", "минут"),
+            Unit::Hours => ("час", "часа", "часов"),
+            Unit::Days => ("день", "дня", "дней"),
+            Unit::Weeks if accusative => ("неделю", "недели", "недель"),
+            Unit::Weeks => ("неделя", "недели", "недель"),
+            Unit::Months => ("месяц", "месяца", "месяцев"),
+            Unit::Years => ("год", "года", "лет"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => many,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Seconds {
+            self.unit(unit, PluralCategory::One, context).into_owned()
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "сейчас".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "вечность".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " и ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} назад", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("через {}", text)
+    }
+}
@@ -0,0 +1,426 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use super::PluralCategory;
+use crate::ParseError;
+
+/// Operands of a number plural rules are evaluated against, as defined by Unicode CLDR
+///
+/// ```
+/// use chrono_humanize::locale::PluralOperands;
+///
+/// let operands: PluralOperands = "-1.50".parse().unwrap();
+/// assert_eq!(1.5, operands.n);
+/// assert_eq!((1, 2, 1, 50, 5), (operands.i, operands.v, operands.w, operands.f, operands.t));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PluralOperands {
+    /// Absolute value of the number
+    pub n: f64,
+    /// Integer digits of `n`
+    pub i: u64,
+    /// Number of visible fraction digits, with trailing zeros
+    pub v: u64,
+    /// Number of visible fraction digits, without trailing zeros
+    pub w: u64,
+    /// Visible fraction digits, with trailing zeros, as an integer
+    pub f: u64,
+    /// Visible fraction digits, without trailing zeros, as an integer
+    pub t: u64,
+    /// Exponent of the compact decimal notation, e.g. 3 for "1.2c3"
+    pub e: u64,
+}
+
+impl From<i64> for PluralOperands {
+    fn from(count: i64) -> Self {
+        Self::from(count.unsigned_abs())
+    }
+}
+
+impl From<i32> for PluralOperands {
+    fn from(count: i32) -> Self {
+        Self::from(i64::from(count))
+    }
+}
+
+impl From<u64> for PluralOperands {
+    fn from(count: u64) -> Self {
+        Self {
+            n: count as f64,
+            i: count,
+            ..Self::default()
+        }
+    }
+}
+
+/// The largest exponent of the compact decimal notation, the larger ones do not fit `i`
+const MAX_EXPONENT: usize = 20;
+
+impl FromStr for PluralOperands {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::new("invalid number", 0..text.len());
+        let digits = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+
+        let unsigned = text.strip_prefix('-').unwrap_or(text);
+        let (mantissa, exponent) = match unsigned.find(['c', 'e']) {
+            Some(idx) => (&unsigned[..idx], &unsigned[idx + 1..]),
+            None => (unsigned, "0"),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let fraction_ok = digits(fraction) || !mantissa.contains('.');
+        if !digits(integer) || !fraction_ok || !digits(exponent) {
+            return Err(invalid());
+        }
+        let e = exponent.parse::<usize>().unwrap_or(usize::MAX);
+        if e > MAX_EXPONENT {
+            let start = text.len() - exponent.len();
+            return Err(ParseError::new("exponent too large", start..text.len()));
+        }
+
+        // Shift the decimal point by the exponent, e.g. "1.2c3" is 1200
+        let shift = e.min(fraction.len());
+        let integer = format!("{}{}{}", integer, &fraction[..shift], "0".repeat(e - shift));
+        let fraction = &fraction[shift..];
+        let trimmed = fraction.trim_end_matches('0');
+
+        let number = |digits: &str| -> Result<u64, ParseError> {
+            if digits.is_empty() {
+                Ok(0)
+            } else {
+                digits.parse().map_err(|_| invalid())
+            }
+        };
+        let n = format!("{}.{}0", integer, fraction)
+            .parse()
+            .map_err(|_| invalid())?;
+
+        Ok(Self {
+            n,
+            i: number(&integer)?,
+            v: fraction.len() as u64,
+            w: trimmed.len() as u64,
+            f: number(fraction)?,
+            t: number(trimmed)?,
+            e: e as u64,
+        })
+    }
+}
+
+/// Plural rules of a language, written in the Unicode CLDR plural rule syntax
+///
+/// Rules are tried in the order they were added, and the count matching none
+/// of them belongs to the "other" category. Samples ("@integer 1, 21, 31, …")
+/// may follow the condition and are ignored.
+///
+/// ```
+/// use chrono_humanize::locale::{PluralCategory, PluralOperands, PluralRules};
+///
+/// let russian = PluralRules::new()
+///     .rule(PluralCategory::One, "v = 0 and i % 10 = 1 and i % 100 != 11")?
+///     .rule(PluralCategory::Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14")?
+///     .rule(PluralCategory::Many, "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14")?;
+///
+/// assert_eq!(PluralCategory::One, russian.select(21));
+/// assert_eq!(PluralCategory::Few, russian.select(3));
+/// assert_eq!(PluralCategory::Many, russian.select(11));
+/// assert_eq!(PluralCategory::Other, russian.select("1.5".parse::<PluralOperands>()?));
+/// # Ok::<(), chrono_humanize::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PluralRules {
+    rules: Vec<(PluralCategory, Condition)>,
+}
+
+impl PluralRules {
+    /// Creates rules putting every number into the "other" category
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rule for the `category`
+    pub fn rule(mut self, category: PluralCategory, rule: &str) -> Result<Self, ParseError> {
+        let condition = Parser::new(rule).parse()?;
+        self.rules.push((category, condition));
+        Ok(self)
+    }
+
+    /// Returns the plural category of the number
+    pub fn select(&self, operands: impl Into<PluralOperands>) -> PluralCategory {
+        let operands = operands.into();
+        self.rules
+            .iter()
+            .find(|(_, condition)| condition.matches(&operands))
+            .map_or(PluralCategory::Other, |(category, _)| *category)
+    }
+}
+
+/// Disjunction of conjunctions of relations
+#[derive(Clone, Debug)]
+struct Condition(Vec<Vec<Relation>>);
+
+impl Condition {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        self.0
+            .iter()
+            .any(|and| and.iter().all(|relation| relation.matches(operands)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operand {
+    N,
+    I,
+    V,
+    W,
+    F,
+    T,
+    E,
+}
+
+#[derive(Clone, Debug)]
+struct Relation {
+    operand: Operand,
+    modulo: Option<u64>,
+    negated: bool,
+    /// Whether the fractional values between the range bounds match as well
+    within: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Relation {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        // Integers stay exact, only the fractional `n` needs the floating point
+        let value = match self.operand {
+            Operand::N if operands.n.fract() != 0.0 => None,
+            Operand::N | Operand::I => Some(operands.i),
+            Operand::V => Some(operands.v),
+            Operand::W => Some(operands.w),
+            Operand::F => Some(operands.f),
+            Operand::T => Some(operands.t),
+            Operand::E => Some(operands.e),
+        };
+        let matched = match value {
+            Some(value) => {
+                let value = self.modulo.map_or(value, |modulo| value % modulo);
+                self.ranges
+                    .iter()
+                    .any(|&(low, high)| low <= value && value <= high)
+            }
+            None => {
+                let value = match self.modulo {
+                    Some(modulo) => operands.n % modulo as f64,
+                    None => operands.n,
+                };
+                self.within
+                    && self
+                        .ranges
+                        .iter()
+                        .any(|&(low, high)| low as f64 <= value && value <= high as f64)
+            }
+        };
+        matched != self.negated
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Number(u64),
+    Range,
+    Comma,
+    Equal,
+    NotEqual,
+    Percent,
+    End,
+}
+
+/// Recursive descent parser of the plural rule syntax
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    token: Token<'a>,
+    span: Range<usize>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        // Samples are for humans and tests, not for the parser
+        let text = &text[..text.find('@').unwrap_or(text.len())];
+        Self {
+            text,
+            pos: 0,
+            token: Token::End,
+            span: 0..0,
+        }
+    }
+
+    fn parse(mut self) -> Result<Condition, ParseError> {
+        self.advance()?;
+        let mut or = vec![];
+        if self.token == Token::End {
+            return Ok(Condition(vec![vec![]]));
+        }
+        loop {
+            let mut and = vec![self.relation()?];
+            while self.token == Token::Word("and") {
+                self.advance()?;
+                and.push(self.relation()?);
+            }
+            or.push(and);
+            match self.token {
+                Token::Word("or") => self.advance()?,
+                Token::End => return Ok(Condition(or)),
+                _ => return Err(self.error("expected 'and' or 'or'")),
+            }
+        }
+    }
+
+    fn relation(&mut self) -> Result<Relation, ParseError> {
+        let operand = match self.token {
+            Token::Word("n") => Operand::N,
+            Token::Word("i") => Operand::I,
+            Token::Word("v") => Operand::V,
+            Token::Word("w") => Operand::W,
+            Token::Word("f") => Operand::F,
+            Token::Word("t") => Operand::T,
+            Token::Word("e") | Token::Word("c") => Operand::E,
+            _ => return Err(self.error("expected operand")),
+        };
+        self.advance()?;
+
+        let modulo = match self.token {
+            Token::Percent | Token::Word("mod") => {
+                self.advance()?;
+                let span = self.span.clone();
+                let modulo = self.number()?;
+                if modulo == 0 {
+                    return Err(ParseError::new("modulo by zero", span));
+                }
+                Some(modulo)
+            }
+            _ => None,
+        };
+
+        let (negated, within) = match self.token {
+            Token::Equal => (false, false),
+            Token::NotEqual => (true, false),
+            Token::Word("is") => {
+                self.advance()?;
+                let negated = self.keyword("not")?;
+                let value = self.number()?;
+                return Ok(Relation {
+                    operand,
+                    modulo,
+                    negated,
+                    within: false,
+                    ranges: vec![(value, value)],
+                });
+            }
+            Token::Word("not") => {
+                self.advance()?;
+                match self.token {
+                    Token::Word("in") => (true, false),
+                    Token::Word("within") => (true, true),
+                    _ => return Err(self.error("expected 'in' or 'within'")),
+                }
+            }
+            Token::Word("in") => (false, false),
+            Token::Word("within") => (false, true),
+            _ => return Err(self.error("expected relation")),
+        };
+        self.advance()?;
+
+        let mut ranges = vec![self.range()?];
+        while self.token == Token::Comma {
+            self.advance()?;
+            ranges.push(self.range()?);
+        }
+
+        Ok(Relation {
+            operand,
+            modulo,
+            negated,
+            within,
+            ranges,
+        })
+    }
+
+    fn range(&mut self) -> Result<(u64, u64), ParseError> {
+        let start = self.span.start;
+        let low = self.number()?;
+        if self.token != Token::Range {
+            return Ok((low, low));
+        }
+        self.advance()?;
+        let high = self.number()?;
+        if high < low {
+            return Err(ParseError::new("empty range", start..self.span.start));
+        }
+        Ok((low, high))
+    }
+
+    fn number(&mut self) -> Result<u64, ParseError> {
+        match self.token {
+            Token::Number(number) => {
+                self.advance()?;
+                Ok(number)
+            }
+            _ => Err(self.error("expected number")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<bool, ParseError> {
+        let found = self.token == Token::Word(keyword);
+        if found {
+            self.advance()?;
+        }
+        Ok(found)
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError::new(message, self.span.clone())
+    }
+
+    /// Moves on to the next token
+    fn advance(&mut self) -> Result<(), ParseError> {
+        let rest = &self.text[self.pos..];
+        let start = self.pos + (rest.len() - rest.trim_start().len());
+        let rest = &self.text[start..];
+
+        let (token, len) = match rest.as_bytes().first() {
+            None => (Token::End, 0),
+            Some(b'.') if rest.starts_with("..") => (Token::Range, 2),
+            Some(b',') => (Token::Comma, 1),
+            Some(b'=') => (Token::Equal, 1),
+            Some(b'!') if rest.starts_with("!=") => (Token::NotEqual, 2),
+            Some(b'%') => (Token::Percent, 1),
+            Some(b) if b.is_ascii_digit() => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let number = rest[..len]
+                    .parse()
+                    .map_err(|_| ParseError::new("number too large", start..start + len))?;
+                (Token::Number(number), len)
+            }
+            Some(b) if b.is_ascii_alphabetic() => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(rest.len());
+                (Token::Word(&rest[..len]), len)
+            }
+            Some(_) => {
+                let len = rest.chars().next().map_or(1, char::len_utf8);
+                return Err(ParseError::new("unexpected character", start..start + len));
+            }
+        };
+
+        self.token = token;
+        self.span = start..start + len;
+        self.pos = start + len;
+        Ok(())
+    }
+}
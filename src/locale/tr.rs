@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+
+/// Turkish language
+///
+/// Nouns stay singular after numerals ("3 saat önce").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Turkish;
+
+impl Locale for Turkish {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::one_other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "ns",
+            Unit::Micros => "µs",
+            Unit::Millis => "ms",
+            Unit::Seconds => "saniye",
+            Unit::Minutes => "dakika",
+            Unit::Hours => "saat",
+            Unit::Days => "gün",
+            Unit::Weeks => "hafta",
+            Unit::Months => "ay",
+            Unit::Years => "yıl",
+        };
+        text.into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('.'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Millis {
+            format!("bir {}", self.unit(unit, PluralCategory::One, context))
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "şimdi".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "sonsuzluk".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " ve ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} önce", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{} sonra", text)
+    }
+}
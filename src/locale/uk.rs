@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use super::{join_list, plural, Context, Locale, NumberFormat, PluralCategory, Unit};
+use crate::Tense;
+
+/// Ukrainian language
+///
+/// Time periods are put into the accusative case in the past ("хвилину тому")
+/// and the future ("за хвилину") tenses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ukrainian;
+
+impl Locale for Ukrainian {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::east_slavic(count)
+    }
+
+    fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+        let accusative = context.tense != Tense::Present;
+        let (one, few, many) = match unit {
+            Unit::Nanos => return "нс".into(),
+            Unit::Micros => return "мкс".into(),
+            Unit::Millis => return "мс".into(),
+            Unit::Seconds if accusative => ("секунду", "секунди", "секунд"),
+            Unit::Seconds => ("секунда", "секунди", "секунд"),
+            Unit::Minutes if accusative => ("хвилину", "хвилини", "хвилин"),
+            Unit::Minutes => ("хвилина", "хвилини", "хвилин"),
+            Unit::Hours if accusative => ("годину", "години", "годин"),
+            Unit::Hours => ("година", "години", "годин"),
+            Unit::Days => ("день", "дні", "днів"),
+            Unit::Weeks => ("тиждень", "тижні", "тижнів"),
+            Unit::Months => ("місяць", "місяці", "місяців"),
+            Unit::Years => ("рік", "роки", "років"),
+        };
+
+        match plural {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            _ => many,
+        }
+        .into()
+    }
+
+    fn number_format(&self) -> NumberFormat {
+        NumberFormat::new(Some('\u{a0}'), ',')
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        if context.accuracy.is_rough() && count == 1 && unit > Unit::Seconds {
+            self.unit(unit, PluralCategory::One, context).into_owned()
+        } else {
+            format!(
+                "{} {}",
+                self.number(count),
+                self.unit(unit, self.plural(count), context)
+            )
+        }
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "зараз".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "вічність".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        join_list(parts, ", ", " і ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{} тому", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("за {}", text)
+    }
+}
@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use super::{plural, Context, Locale, PluralCategory, Unit};
+
+/// Chinese language written in simplified characters (zh-Hans)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SimplifiedChinese;
+
+impl Locale for SimplifiedChinese {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "纳秒",
+            Unit::Micros => "微秒",
+            Unit::Millis => "毫秒",
+            Unit::Seconds => "秒钟",
+            Unit::Minutes => "分钟",
+            Unit::Hours => "小时",
+            Unit::Days => "天",
+            Unit::Weeks => "周",
+            Unit::Months => "个月",
+            Unit::Years => "年",
+        };
+        text.into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        format!(
+            "{}{}",
+            self.number(count),
+            self.unit(unit, self.plural(count), context)
+        )
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "现在".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "永远".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        parts.concat()
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{}前", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{}后", text)
+    }
+}
+
+/// Chinese language written in traditional characters (zh-Hant)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TraditionalChinese;
+
+impl Locale for TraditionalChinese {
+    fn plural(&self, count: i64) -> PluralCategory {
+        plural::other(count)
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "奈秒",
+            Unit::Micros => "微秒",
+            Unit::Millis => "毫秒",
+            Unit::Seconds => "秒",
+            Unit::Minutes => "分鐘",
+            Unit::Hours => "小時",
+            Unit::Days => "天",
+            Unit::Weeks => "週",
+            Unit::Months => "個月",
+            Unit::Years => "年",
+        };
+        text.into()
+    }
+
+    fn period(&self, unit: Unit, count: i64, context: Context) -> String {
+        format!(
+            "{}{}",
+            self.number(count),
+            self.unit(unit, self.plural(count), context)
+        )
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "現在".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "永遠".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        parts.concat()
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("{}前", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("{}後", text)
+    }
+}
@@ -0,0 +1,365 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use chrono::Duration;
+
+use crate::locale::{Context, English, Locale, PluralCategory, Unit};
+use crate::{Accuracy, HumanTime, ParseError, Tense};
+
+pub use self::date::parse_date;
+
+mod date;
+mod iso8601;
+mod localized;
+
+/// Parses the English text `HumanTime` is displayed as, e.g. "an hour ago",
+/// "in 3 weeks", "now" or "1 month, 2 weeks and 1 day"
+///
+/// Months are 30 days long and years are 365 days long, just like in the text
+/// representation. The words are case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use chrono_humanize::HumanTime;
+///
+/// let ht: HumanTime = "2 days and 4 hours ago".parse().unwrap();
+/// assert_eq!(Duration::hours(-52), Duration::from(ht));
+///
+/// let err = "in 3 fortnights".parse::<HumanTime>().unwrap_err();
+/// assert_eq!("expected unit at 5..15", err.to_string());
+/// ```
+impl FromStr for HumanTime {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(text)?;
+        parser.humantime()
+    }
+}
+
+impl HumanTime {
+    /// Parses the compact duration span, such as "1h30m", "2 days 4 hours", "1.5h",
+    /// "250ms" or "90min", the way systemd and Go write them
+    ///
+    /// The span is a sequence of numbers, possibly with fractions, each followed by a unit,
+    /// and may start with a sign. The units are:
+    ///
+    /// - "ns", "nsec", "nanosecond", "nanoseconds"
+    /// - "us", "µs", "usec", "microsecond", "microseconds"
+    /// - "ms", "msec", "millisecond", "milliseconds"
+    /// - "s", "sec", "secs", "second", "seconds"
+    /// - "m", "min", "mins", "minute", "minutes"
+    /// - "h", "hr", "hrs", "hour", "hours"
+    /// - "d", "day", "days"
+    /// - "w", "week", "weeks"
+    /// - "M", "month", "months", 30 days each
+    /// - "y", "yr", "yrs", "year", "years", 365 days each
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let ht = HumanTime::parse_span("1h30m").unwrap();
+    /// assert_eq!(Duration::minutes(90), Duration::from(ht));
+    ///
+    /// let ht = HumanTime::parse_span("-1.5 days").unwrap();
+    /// assert_eq!(Duration::hours(-36), Duration::from(ht));
+    ///
+    /// let err = HumanTime::parse_span("2h 5x").unwrap_err();
+    /// assert_eq!("unknown unit at 4..5", err.to_string());
+    /// ```
+    pub fn parse_span(text: &str) -> Result<Self, ParseError> {
+        let skip = |pos: usize| pos + (text[pos..].len() - text[pos..].trim_start().len());
+        let token = |pos: usize, f: fn(char) -> bool| {
+            pos + text[pos..]
+                .find(|c: char| !f(c))
+                .unwrap_or(text.len() - pos)
+        };
+
+        let mut pos = skip(0);
+        let negative = text[pos..].starts_with('-');
+        if negative || text[pos..].starts_with('+') {
+            pos += 1;
+        }
+
+        let mut duration = Duration::zero();
+        for first in std::iter::once(true).chain(std::iter::repeat(false)) {
+            pos = skip(pos);
+            let start = pos;
+            pos = token(pos, |c| c.is_ascii_digit() || c == '.');
+            if start == pos {
+                let end = match text[pos..].chars().next() {
+                    None if first => start,
+                    None => break,
+                    Some(c) if c.is_alphabetic() => token(pos, char::is_alphabetic),
+                    Some(c) => pos + c.len_utf8(),
+                };
+                return Err(ParseError::new("expected number", start..end));
+            }
+            let number = start..pos;
+
+            pos = skip(pos);
+            let unit_start = pos;
+            pos = token(pos, char::is_alphabetic);
+            if unit_start == pos {
+                return Err(ParseError::new("missing unit", number));
+            }
+            let unit = span_unit(&text[unit_start..pos])
+                .ok_or_else(|| ParseError::new("unknown unit", unit_start..pos))?;
+
+            let period = fraction(&text[number.clone()], unit)
+                .map_err(|message| ParseError::new(message, number))?;
+            duration = duration
+                .checked_add(&period)
+                .ok_or_else(|| ParseError::new("duration too long", start..pos))?;
+        }
+
+        Ok(Self::from(if negative { -duration } else { duration }))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Number(i64),
+    Word(&'a str),
+    Comma,
+    Symbol(char),
+    End,
+}
+
+#[derive(Clone)]
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    token: Token<'a>,
+    span: Range<usize>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Result<Self, ParseError> {
+        let mut parser = Self {
+            text,
+            pos: 0,
+            token: Token::End,
+            span: 0..0,
+        };
+        parser.advance()?;
+        Ok(parser)
+    }
+
+    fn humantime(&mut self) -> Result<HumanTime, ParseError> {
+        let start = self.span.start;
+        let future = self.keyword("in")?;
+        let mut duration = Duration::zero();
+        // "in now" and "now ago" are the present moment put in a tense
+        if !self.keyword("now")? {
+            loop {
+                let period = self.period()?;
+                duration = duration
+                    .checked_add(&period)
+                    .ok_or_else(|| ParseError::new("duration too long", start..self.span.start))?;
+
+                let comma = self.token == Token::Comma;
+                if comma {
+                    self.advance()?;
+                }
+                if !self.keyword("and")? && !comma {
+                    break;
+                }
+            }
+        }
+
+        let ago = self.span.clone();
+        if self.keyword("ago")? {
+            if future {
+                return Err(ParseError::new("both 'in' and 'ago'", ago));
+            }
+            duration = -duration;
+        }
+        self.end()?;
+
+        Ok(HumanTime::from(duration))
+    }
+
+    /// Parses a single period, e.g. "3 hours" or "an hour"
+    fn period(&mut self) -> Result<Duration, ParseError> {
+        let (unit, count, span) = self.unit_count()?;
+        unit.duration(count)
+            .ok_or_else(|| ParseError::new("duration too long", span))
+    }
+
+    /// Parses the count and the unit of a single period along with its span
+    fn unit_count(&mut self) -> Result<(Unit, i64, Range<usize>), ParseError> {
+        let start = self.span.start;
+        let count = match self.token {
+            Token::Number(count) => count,
+            Token::Word(word)
+                if word.eq_ignore_ascii_case("a") || word.eq_ignore_ascii_case("an") =>
+            {
+                1
+            }
+            _ => return Err(self.error("expected number")),
+        };
+        self.advance()?;
+
+        let unit = match self.token {
+            Token::Word(word) => unit(word),
+            _ => None,
+        }
+        .ok_or_else(|| self.error("expected unit"))?;
+        let span = start..self.span.end;
+        self.advance()?;
+
+        Ok((unit, count, span))
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<bool, ParseError> {
+        let found = matches!(self.token, Token::Word(word) if word.eq_ignore_ascii_case(keyword));
+        if found {
+            self.advance()?;
+        }
+        Ok(found)
+    }
+
+    fn end(&self) -> Result<(), ParseError> {
+        match self.token {
+            Token::End => Ok(()),
+            _ => Err(self.error("unexpected text")),
+        }
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError::new(message, self.span.clone())
+    }
+
+    /// Moves on to the next token
+    fn advance(&mut self) -> Result<(), ParseError> {
+        let rest = &self.text[self.pos..];
+        let start = self.pos + (rest.len() - rest.trim_start().len());
+        let rest = &self.text[start..];
+
+        let (token, len) = match rest.chars().next() {
+            None => (Token::End, 0),
+            Some(',') => (Token::Comma, 1),
+            Some(c @ '-') | Some(c @ ':') => (Token::Symbol(c), 1),
+            Some(c) if c.is_ascii_digit() => {
+                let len = number_len(rest);
+                let number = rest[..len]
+                    .replace(',', "")
+                    .parse()
+                    .map_err(|_| ParseError::new("number too large", start..start + len))?;
+                (Token::Number(number), len)
+            }
+            Some(c) if c.is_alphabetic() => {
+                let len = rest
+                    .find(|c: char| !c.is_alphabetic())
+                    .unwrap_or(rest.len());
+                (Token::Word(&rest[..len]), len)
+            }
+            Some(c) => {
+                return Err(ParseError::new(
+                    "unexpected character",
+                    start..start + c.len_utf8(),
+                ))
+            }
+        };
+
+        self.token = token;
+        self.span = start..start + len;
+        self.pos = start + len;
+        Ok(())
+    }
+}
+
+/// Returns the length of the number at the start of `text`, including the commas
+/// between the groups of three digits, as in "1,234"
+fn number_len(text: &str) -> usize {
+    let digits = |text: &str| {
+        text.find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len())
+    };
+    let mut len = digits(text);
+    while text[len..].starts_with(',') && digits(&text[len + 1..]) == 3 {
+        len += 4;
+    }
+    len
+}
+
+/// Returns the unit named `word` in English, singular or plural
+fn unit(word: &str) -> Option<Unit> {
+    const UNITS: [Unit; 10] = [
+        Unit::Nanos,
+        Unit::Micros,
+        Unit::Millis,
+        Unit::Seconds,
+        Unit::Minutes,
+        Unit::Hours,
+        Unit::Days,
+        Unit::Weeks,
+        Unit::Months,
+        Unit::Years,
+    ];
+    let context = Context::new(Accuracy::Precise, Tense::Present);
+
+    UNITS.iter().copied().find(|&unit| {
+        [PluralCategory::One, PluralCategory::Other]
+            .iter()
+            .any(|&plural| word.eq_ignore_ascii_case(&English.unit(unit, plural, context)))
+    })
+}
+
+/// Returns the unit of the duration span named `word`
+fn span_unit(word: &str) -> Option<Unit> {
+    // "M" is the month and "m" is the minute
+    if word == "M" {
+        return Some(Unit::Months);
+    }
+    let unit = match word.to_lowercase().as_str() {
+        "ns" | "nsec" | "nanosecond" | "nanoseconds" => Unit::Nanos,
+        "us" | "µs" | "μs" | "usec" | "microsecond" | "microseconds" => Unit::Micros,
+        "ms" | "msec" | "millisecond" | "milliseconds" => Unit::Millis,
+        "s" | "sec" | "secs" | "second" | "seconds" => Unit::Seconds,
+        "m" | "min" | "mins" | "minute" | "minutes" => Unit::Minutes,
+        "h" | "hr" | "hrs" | "hour" | "hours" => Unit::Hours,
+        "d" | "day" | "days" => Unit::Days,
+        "w" | "week" | "weeks" => Unit::Weeks,
+        "month" | "months" => Unit::Months,
+        "y" | "yr" | "yrs" | "year" | "years" => Unit::Years,
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// Returns the duration of the possibly fractional `number` of units, e.g. "1.5"
+fn fraction(number: &str, unit: Unit) -> Result<Duration, &'static str> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return Err("invalid number");
+    }
+
+    let whole = match whole {
+        "" => 0,
+        whole => whole.parse().map_err(|_| "number too large")?,
+    };
+    let whole = unit.duration(whole).ok_or("duration too long")?;
+
+    // Nanoseconds are the smallest unit, the digits past them do not matter
+    let digits = &fraction[..fraction.len().min(18)];
+    if digits.is_empty() {
+        return Ok(whole);
+    }
+    let nanos = unit
+        .duration(1)
+        .and_then(|unit| unit.num_nanoseconds())
+        .map_or(0, i128::from);
+    let numerator: i128 = digits.parse().map_err(|_| "invalid number")?;
+    let part = nanos * numerator / 10_i128.pow(digits.len() as u32);
+    // A fraction of a unit is shorter than the unit itself, so it fits
+    let part = Duration::nanoseconds(part as i64);
+
+    whole.checked_add(&part).ok_or("duration too long")
+}
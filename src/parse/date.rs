@@ -0,0 +1,358 @@
+use std::convert::TryFrom;
+use std::ops::Range;
+
+use chrono::{
+    DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Weekday,
+};
+
+use super::{unit, Parser, Token};
+use crate::locale::Unit;
+use crate::ParseError;
+
+/// Parses the English phrase naming a moment, such as "tomorrow at 5pm", "next tuesday",
+/// "last month" or "3 days before 2025-01-01", relative to the `reference` moment
+///
+/// The phrase is read in the time zone of the `reference`, and so is the result.
+/// It is one of:
+///
+/// - "now", "in 3 hours", "2 days and 4 hours ago"
+/// - "3 days before 2025-01-01", "a week after tomorrow" or after any other phrase
+/// - a day, optionally followed by "at" and the time of day: "today", "tomorrow",
+///   "yesterday", "2025-01-01", "tuesday" (the next one), "next tuesday",
+///   "last tuesday", "this tuesday" (the one of the current week, starting on Monday),
+///   "next week", "last month", "this year"
+/// - the time of day today: "5pm", "at 5:30 am", "17:00", "noon", "midnight"
+///
+/// The days start at midnight unless the time is given, while "next week", "last month"
+/// and the like keep the time of the `reference`. Unlike in the `HumanTime` text, months
+/// and years are calendar ones here, so a month after January 31 is the last day of
+/// February. The words are case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use chrono_humanize::{parse_date, HumanTime};
+///
+/// let tz = FixedOffset::east_opt(3600).unwrap();
+/// // Tuesday
+/// let reference = tz.with_ymd_and_hms(2024, 12, 31, 9, 30, 0).unwrap();
+///
+/// let date = parse_date("tomorrow at 5pm", &reference).unwrap();
+/// assert_eq!(tz.with_ymd_and_hms(2025, 1, 1, 17, 0, 0).unwrap(), date);
+/// assert_eq!("in a day", HumanTime::from(date - reference).to_string());
+///
+/// let date = parse_date("3 days before 2025-01-01", &reference).unwrap();
+/// assert_eq!(tz.with_ymd_and_hms(2024, 12, 29, 0, 0, 0).unwrap(), date);
+///
+/// let err = parse_date("next fortnight", &reference).unwrap_err();
+/// assert_eq!("expected weekday, week, month or year at 5..14", err.to_string());
+/// ```
+pub fn parse_date<Tz: TimeZone>(
+    text: &str,
+    reference: &DateTime<Tz>,
+) -> Result<DateTime<Tz>, ParseError> {
+    let mut parser = Parser::new(text)?;
+    let moment = parser.moment(reference)?;
+    parser.end()?;
+    Ok(moment)
+}
+
+impl Parser<'_> {
+    fn moment<Tz: TimeZone>(
+        &mut self,
+        reference: &DateTime<Tz>,
+    ) -> Result<DateTime<Tz>, ParseError> {
+        if self.keyword("now")? {
+            return Ok(reference.clone());
+        }
+
+        let start = self.span.start;
+        if self.keyword("in")? {
+            let periods = self.periods()?;
+            let span = start..self.previous_end();
+            return shift(reference.clone(), &periods, 1, span);
+        }
+
+        if self.at_period()? {
+            let periods = self.periods()?;
+            let span = start..self.previous_end();
+            if self.keyword("ago")? {
+                return shift(reference.clone(), &periods, -1, span);
+            }
+            let sign = if self.keyword("before")? {
+                -1
+            } else if self.keyword("after")? {
+                1
+            } else {
+                return Err(self.error("expected 'ago', 'before' or 'after'"));
+            };
+            let moment = self.moment(reference)?;
+            return shift(moment, &periods, sign, span);
+        }
+
+        let local = match self.day(reference)? {
+            Some(day) if self.keyword("at")? => day.date().and_time(self.time()?),
+            Some(day) => day,
+            None => {
+                self.keyword("at")?;
+                reference.naive_local().date().and_time(self.time()?)
+            }
+        };
+        resolve(&reference.timezone(), local, start..self.previous_end())
+    }
+
+    /// Parses the periods separated by commas and "and", e.g. "1 day, 2 hours and 5 minutes"
+    fn periods(&mut self) -> Result<Vec<(Unit, i64)>, ParseError> {
+        let mut periods = vec![];
+        loop {
+            let (unit, count, _) = self.unit_count()?;
+            periods.push((unit, count));
+
+            let comma = self.token == Token::Comma;
+            if comma {
+                self.advance()?;
+            }
+            if !self.keyword("and")? && !comma {
+                break;
+            }
+        }
+        Ok(periods)
+    }
+
+    /// Tells whether a period starts here, that is a number or an article followed by a unit
+    fn at_period(&self) -> Result<bool, ParseError> {
+        match self.token {
+            Token::Number(_) => {}
+            Token::Word(word)
+                if word.eq_ignore_ascii_case("a") || word.eq_ignore_ascii_case("an") => {}
+            _ => return Ok(false),
+        }
+        let mut ahead = self.clone();
+        ahead.advance()?;
+        Ok(matches!(ahead.token, Token::Word(word) if unit(word).is_some()))
+    }
+
+    /// Parses the day, if any, at the time of day it starts
+    fn day<Tz: TimeZone>(
+        &mut self,
+        reference: &DateTime<Tz>,
+    ) -> Result<Option<NaiveDateTime>, ParseError> {
+        let now = reference.naive_local();
+        let today = now.date().and_time(NaiveTime::MIN);
+
+        let word = match self.token {
+            Token::Number(_) => {
+                let mut ahead = self.clone();
+                ahead.advance()?;
+                if ahead.token != Token::Symbol('-') {
+                    return Ok(None);
+                }
+                return self.date().map(Some);
+            }
+            Token::Word(word) => word.to_lowercase(),
+            _ => return Ok(None),
+        };
+
+        let day = match word.as_str() {
+            "today" => Some(today),
+            "tomorrow" => calendar(today, 0, 1),
+            "yesterday" => calendar(today, 0, -1),
+            "next" | "last" | "this" => {
+                self.advance()?;
+                let sign = match word.as_str() {
+                    "next" => 1,
+                    "last" => -1,
+                    _ => 0,
+                };
+                let word = match self.token {
+                    Token::Word(word) => word.to_lowercase(),
+                    _ => String::new(),
+                };
+                match (word.as_str(), word.parse::<Weekday>()) {
+                    ("week", _) => calendar(now, 0, 7 * sign),
+                    ("month", _) => calendar(now, sign, 0),
+                    ("year", _) => calendar(now, 12 * sign, 0),
+                    (_, Ok(weekday)) => calendar(today, 0, weekday_offset(today, weekday, sign)),
+                    _ => return Err(self.error("expected weekday, week, month or year")),
+                }
+            }
+            _ => match word.parse::<Weekday>() {
+                Ok(weekday) => calendar(today, 0, weekday_offset(today, weekday, 1)),
+                Err(_) => return Ok(None),
+            },
+        }
+        .ok_or_else(|| self.error("date out of range"))?;
+        self.advance()?;
+
+        Ok(Some(day))
+    }
+
+    /// Parses the date in the ISO 8601 format, e.g. "2025-01-01"
+    fn date(&mut self) -> Result<NaiveDateTime, ParseError> {
+        let start = self.span.start;
+        let year = self.number()?;
+        self.symbol('-')?;
+        let month = self.number()?;
+        self.symbol('-')?;
+        let day = self.number()?;
+
+        i32::try_from(year)
+            .ok()
+            .zip(u32::try_from(month).ok())
+            .zip(u32::try_from(day).ok())
+            .and_then(|((year, month), day)| NaiveDate::from_ymd_opt(year, month, day))
+            .map(|date| date.and_time(NaiveTime::MIN))
+            .ok_or_else(|| ParseError::new("invalid date", start..self.previous_end()))
+    }
+
+    /// Parses the time of day, e.g. "5pm", "5:30 am", "17:00" or "noon"
+    fn time(&mut self) -> Result<NaiveTime, ParseError> {
+        if self.keyword("midnight")? {
+            return Ok(NaiveTime::MIN);
+        }
+
+        let start = self.span.start;
+        let (mut hour, mut minute) = (12, 0);
+        if !self.keyword("noon")? {
+            hour = match self.token {
+                Token::Number(hour) => hour,
+                _ => return Err(self.error("expected date or time")),
+            };
+            self.advance()?;
+            if self.token == Token::Symbol(':') {
+                self.advance()?;
+                minute = match self.token {
+                    Token::Number(minute) if self.span.len() == 2 => minute,
+                    _ => return Err(self.error("expected minutes")),
+                };
+                self.advance()?;
+            }
+
+            if self.keyword("pm")? {
+                hour = if (1..=12).contains(&hour) {
+                    hour % 12 + 12
+                } else {
+                    -1
+                };
+            } else if self.keyword("am")? {
+                hour = if (1..=12).contains(&hour) {
+                    hour % 12
+                } else {
+                    -1
+                };
+            }
+        }
+
+        u32::try_from(hour)
+            .ok()
+            .zip(u32::try_from(minute).ok())
+            .and_then(|(hour, minute)| NaiveTime::from_hms_opt(hour, minute, 0))
+            .ok_or_else(|| ParseError::new("invalid time", start..self.previous_end()))
+    }
+
+    fn number(&mut self) -> Result<i64, ParseError> {
+        match self.token {
+            Token::Number(number) => {
+                self.advance()?;
+                Ok(number)
+            }
+            _ => Err(self.error("expected number")),
+        }
+    }
+
+    fn symbol(&mut self, symbol: char) -> Result<(), ParseError> {
+        if self.token != Token::Symbol(symbol) {
+            return Err(self.error("expected date"));
+        }
+        self.advance()
+    }
+
+    /// Returns where the last parsed token ends
+    fn previous_end(&self) -> usize {
+        self.text[..self.span.start].trim_end().len()
+    }
+}
+
+/// Returns the number of days from `today` to the `weekday`, the next one (`sign` 1),
+/// the last one (`sign` -1) or the one of the current week (`sign` 0)
+fn weekday_offset(today: NaiveDateTime, weekday: Weekday, sign: i64) -> i64 {
+    let today = i64::from(today.weekday().num_days_from_monday());
+    let weekday = i64::from(weekday.num_days_from_monday());
+    match sign {
+        1 => (weekday - today + 6).rem_euclid(7) + 1,
+        -1 => -((today - weekday + 6).rem_euclid(7) + 1),
+        _ => weekday - today,
+    }
+}
+
+/// Moves the local `moment` by the calendar `months` and `days`
+fn calendar(moment: NaiveDateTime, months: i64, days: i64) -> Option<NaiveDateTime> {
+    let abs_months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    let moment = if months < 0 {
+        moment.checked_sub_months(abs_months)?
+    } else {
+        moment.checked_add_months(abs_months)?
+    };
+
+    let abs_days = Days::new(days.unsigned_abs());
+    if days < 0 {
+        moment.checked_sub_days(abs_days)
+    } else {
+        moment.checked_add_days(abs_days)
+    }
+}
+
+/// Moves the `moment` by the `periods` forward (`sign` 1) or backward (`sign` -1)
+///
+/// Days and longer periods move the local time, so that a day after 5pm is 5pm
+/// even across a daylight saving time change.
+fn shift<Tz: TimeZone>(
+    moment: DateTime<Tz>,
+    periods: &[(Unit, i64)],
+    sign: i64,
+    span: Range<usize>,
+) -> Result<DateTime<Tz>, ParseError> {
+    let out_of_range = || ParseError::new("date out of range", span.clone());
+
+    let (mut months, mut days, mut duration) = (0_i64, 0_i64, Duration::zero());
+    for &(unit, count) in periods {
+        let count = sign * count;
+        let total = match unit {
+            Unit::Years => count.checked_mul(12).and_then(|n| months.checked_add(n)),
+            Unit::Months => months.checked_add(count),
+            Unit::Weeks => count.checked_mul(7).and_then(|n| days.checked_add(n)),
+            Unit::Days => days.checked_add(count),
+            _ => {
+                duration = unit
+                    .duration(count)
+                    .and_then(|period| duration.checked_add(&period))
+                    .ok_or_else(out_of_range)?;
+                continue;
+            }
+        }
+        .ok_or_else(out_of_range)?;
+        match unit {
+            Unit::Years | Unit::Months => months = total,
+            _ => days = total,
+        }
+    }
+
+    let local = calendar(moment.naive_local(), months, days).ok_or_else(out_of_range)?;
+    resolve(&moment.timezone(), local, span.clone())?
+        .checked_add_signed(duration)
+        .ok_or_else(out_of_range)
+}
+
+/// Returns the moment of the `local` time in the time zone `tz`, the earlier one
+/// when the clocks are turned back
+fn resolve<Tz: TimeZone>(
+    tz: &Tz,
+    local: NaiveDateTime,
+    span: Range<usize>,
+) -> Result<DateTime<Tz>, ParseError> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .ok_or_else(|| ParseError::new("nonexistent local time", span))
+}
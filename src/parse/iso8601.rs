@@ -0,0 +1,119 @@
+use chrono::Duration;
+
+use super::fraction;
+use crate::locale::Unit;
+use crate::{HumanTime, ParseError};
+
+/// The designators of the date components followed by the ones of the time components
+const DESIGNATORS: [(char, Unit); 7] = [
+    ('Y', Unit::Years),
+    ('M', Unit::Months),
+    ('W', Unit::Weeks),
+    ('D', Unit::Days),
+    ('H', Unit::Hours),
+    ('M', Unit::Minutes),
+    ('S', Unit::Seconds),
+];
+
+/// The index of the first time component in `DESIGNATORS`
+const TIME: usize = 4;
+
+impl HumanTime {
+    /// Parses the ISO 8601 duration, e.g. "P1Y2M3DT4H5M6.5S", "-P1D" or "P2W"
+    ///
+    /// Years are 365 days long and months are 30 days long, just like in the text
+    /// representation. The weeks may be given next to the other components, and the last
+    /// component may have a fraction, written with either a dot or a comma.
+    /// The duration may start with a sign, as in XML Schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Duration;
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let ht = HumanTime::parse_iso8601("P1M15DT6.5S").unwrap();
+    /// assert_eq!(Duration::days(45) + Duration::milliseconds(6500), Duration::from(ht));
+    ///
+    /// let ht = HumanTime::parse_iso8601("-PT1,5H").unwrap();
+    /// assert_eq!(Duration::minutes(-90), Duration::from(ht));
+    ///
+    /// let err = HumanTime::parse_iso8601("P1D2Y").unwrap_err();
+    /// assert_eq!("component out of order at 4..5", err.to_string());
+    /// ```
+    pub fn parse_iso8601(text: &str) -> Result<Self, ParseError> {
+        let char_span =
+            |pos: usize| pos..pos + text[pos..].chars().next().map_or(0, char::len_utf8);
+
+        let negative = text.starts_with('-');
+        let mut pos = usize::from(negative || text.starts_with('+'));
+        if !text[pos..].starts_with('P') {
+            return Err(ParseError::new("expected 'P'", char_span(pos)));
+        }
+        pos += 1;
+
+        let mut duration = Duration::zero();
+        // The index of the first designator allowed next
+        let mut next = 0;
+        let mut fraction_span = None;
+        let mut components = 0;
+        let mut time = false;
+        while pos < text.len() {
+            if !time && text[pos..].starts_with('T') {
+                time = true;
+                next = TIME;
+                components = 0;
+                pos += 1;
+                continue;
+            }
+
+            let start = pos;
+            pos += text[pos..]
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .unwrap_or(text.len() - pos);
+            if start == pos {
+                return Err(ParseError::new("expected number", char_span(pos)));
+            }
+            let number = start..pos;
+
+            let designator = text[pos..].chars().next();
+            let mut range = if time {
+                TIME..DESIGNATORS.len()
+            } else {
+                0..TIME
+            };
+            let idx = range
+                .find(|&idx| Some(DESIGNATORS[idx].0) == designator)
+                .ok_or_else(|| ParseError::new("expected designator", char_span(pos)))?;
+            if idx < next {
+                return Err(ParseError::new("component out of order", char_span(pos)));
+            }
+            if let Some(span) = fraction_span.take() {
+                return Err(ParseError::new(
+                    "only the last component may have a fraction",
+                    span,
+                ));
+            }
+
+            let digits = text[number.clone()].replace(',', ".");
+            if digits.contains('.') {
+                fraction_span = Some(number.clone());
+            }
+            let period = fraction(&digits, DESIGNATORS[idx].1)
+                .map_err(|message| ParseError::new(message, number))?;
+            pos += 1;
+            duration = duration
+                .checked_add(&period)
+                .ok_or_else(|| ParseError::new("duration too long", start..pos))?;
+
+            next = idx + 1;
+            components += 1;
+        }
+
+        if components == 0 {
+            return Err(ParseError::new("expected number", pos..pos));
+        }
+
+        Ok(Self::from(if negative { -duration } else { duration }))
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use chrono::Duration;
+
+use crate::locale::{Context, Locale, NumberFormat, Unit};
+use crate::{Accuracy, HumanTime, ParseError, Tense};
+
+/// Stands for the text put into a tense or joined into a list
+const MARK: &str = "\u{E000}";
+
+const UNITS: [Unit; 10] = [
+    Unit::Years,
+    Unit::Months,
+    Unit::Weeks,
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Millis,
+    Unit::Micros,
+    Unit::Nanos,
+];
+
+impl HumanTime {
+    /// Parses the text `HumanTime` is displayed as in the given `locale`, e.g.
+    /// "il y a 3 heures" in French or "vor 2 Tagen" in German
+    ///
+    /// The text is matched against the one the `locale` produces for every unit,
+    /// plural form, accuracy and tense, so whatever the locale can write, it can read.
+    /// Letter case and the kinds of spaces do not matter. The tense has to put the text
+    /// between the same words every time, which `PseudoLocale` does not do, and the text
+    /// that reads the same in the past and in the future is taken to be in the past.
+    /// Months are 30 days long and years are 365 days long, just like in the text
+    /// representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "locale-de", feature = "locale-fr"))]
+    /// # {
+    /// use chrono::Duration;
+    /// use chrono_humanize::locale::{French, German};
+    /// use chrono_humanize::HumanTime;
+    ///
+    /// let ht = HumanTime::from_text("il y a 3 heures", &French).unwrap();
+    /// assert_eq!(Duration::hours(-3), Duration::from(ht));
+    ///
+    /// let ht = HumanTime::from_text("in 1 Monat, 2 Wochen und 1 Tag", &German).unwrap();
+    /// assert_eq!(Duration::days(45), Duration::from(ht));
+    ///
+    /// let err = HumanTime::from_text("vor 2 Tagn", &German).unwrap_err();
+    /// assert_eq!("unrecognized period at 4..10", err.to_string());
+    /// # }
+    /// ```
+    pub fn from_text(text: &str, locale: &dyn Locale) -> Result<Self, ParseError> {
+        let start = text.len() - text.trim_start().len();
+        let end = text.trim_end().len().max(start);
+        let text = &text[start..end];
+        let counts = counts(text, locale.number_format());
+
+        let mut furthest = 0;
+        for &tense in &[Tense::Past, Tense::Future, Tense::Present] {
+            let now = in_tense(locale, tense, locale.now().into_owned());
+            if matches(text.chars(), now.chars()) == Some(text.len())
+                || matches(text.chars(), locale.now().chars()) == Some(text.len())
+            {
+                return Ok(Self::now());
+            }
+
+            let marked = in_tense(locale, tense, MARK.to_string());
+            let (prefix, suffix) = marked.split_once(MARK).unwrap_or(("", ""));
+            let body_start = match matches(text.chars(), prefix.chars()) {
+                Some(len) => len,
+                None => continue,
+            };
+            let body_end = match matches(text.chars().rev(), suffix.chars().rev()) {
+                Some(len) if text.len() - len >= body_start => text.len() - len,
+                _ => continue,
+            };
+
+            let body = &text[body_start..body_end];
+            let mut matcher = Matcher {
+                locale,
+                text: body,
+                tense,
+                counts: &counts,
+                list: Vec::new(),
+                texts: HashMap::new(),
+                furthest: 0,
+            };
+            let mut periods = None;
+            for len in 1..=UNITS.len() {
+                matcher.list = list(locale, len);
+                // The locale does not keep the periods of the list apart
+                if matcher.list.len() != len + 1 {
+                    continue;
+                }
+                periods = matches(body.chars(), matcher.list[0].chars())
+                    .and_then(|pos| matcher.periods(pos, 0));
+                if periods.is_some() {
+                    break;
+                }
+            }
+            furthest = furthest.max(body_start + matcher.furthest);
+
+            if let Some(periods) = periods {
+                let span = start + body_start..start + body_end;
+                let duration = periods
+                    .iter()
+                    .try_fold(Duration::zero(), |duration, &(unit, count)| {
+                        unit.duration(count)
+                            .and_then(|period| duration.checked_add(&period))
+                    })
+                    .ok_or_else(|| ParseError::new("duration too long", span))?;
+                return Ok(Self::from(match tense {
+                    Tense::Past => -duration,
+                    Tense::Future | Tense::Present => duration,
+                }));
+            }
+        }
+
+        Err(ParseError::new(
+            "unrecognized period",
+            start + furthest..end,
+        ))
+    }
+}
+
+/// Every period the locale may write at some place of the list, along with its text
+type Texts = Rc<[(Unit, i64, String)]>;
+
+/// Matches the list of periods against the text the locale produces
+struct Matcher<'a> {
+    locale: &'a dyn Locale,
+    text: &'a str,
+    tense: Tense,
+    counts: &'a [i64],
+    /// The texts the locale puts around and between the periods of the list
+    list: Vec<String>,
+    /// The periods along with their texts, by whether they are the first and the last
+    /// ones of the list
+    texts: HashMap<(bool, bool), Texts>,
+    /// How far the matched periods went
+    furthest: usize,
+}
+
+impl Matcher<'_> {
+    /// Returns the periods, from the `idx`-th one on, the text starting at `pos` consists of
+    fn periods(&mut self, pos: usize, idx: usize) -> Option<Vec<(Unit, i64)>> {
+        let rest = &self.text[pos..];
+        let last = idx + 2 == self.list.len();
+
+        for &(unit, count, ref period) in self.texts(idx == 0, last).iter() {
+            let end = match matches(rest.chars(), period.chars()) {
+                Some(len) => pos + len,
+                None => continue,
+            };
+            self.furthest = self.furthest.max(end);
+
+            let after = self.list[idx + 1].chars();
+            let next = match matches(self.text[end..].chars(), after) {
+                Some(len) => end + len,
+                None => continue,
+            };
+            if last {
+                if next == self.text.len() {
+                    return Some(vec![(unit, count)]);
+                }
+            } else if let Some(mut periods) = self.periods(next, idx + 1) {
+                periods.insert(0, (unit, count));
+                return Some(periods);
+            }
+        }
+        None
+    }
+
+    /// Returns every period the locale may write at the given place of the list
+    /// along with its text, which is built once per matcher
+    fn texts(&mut self, first: bool, last: bool) -> Texts {
+        let (locale, tense, counts) = (self.locale, self.tense, self.counts);
+        let texts = self.texts.entry((first, last)).or_insert_with(|| {
+            let mut texts = Vec::new();
+            for &accuracy in &[Accuracy::Rough, Accuracy::Precise] {
+                let context = Context {
+                    first,
+                    last,
+                    ..Context::new(accuracy, tense)
+                };
+                for &unit in &UNITS {
+                    for &count in counts {
+                        texts.push((unit, count, locale.period(unit, count, context)));
+                    }
+                }
+            }
+            texts.into()
+        });
+        Rc::clone(texts)
+    }
+}
+
+/// Puts the `text` into the `tense` of the `locale`
+fn in_tense(locale: &dyn Locale, tense: Tense, text: String) -> String {
+    match tense {
+        Tense::Past => locale.past(text),
+        Tense::Future => locale.future(text),
+        Tense::Present => locale.present(text),
+    }
+}
+
+/// Returns the texts the `locale` puts before, between and after the `len` periods
+/// of a list
+fn list(locale: &dyn Locale, len: usize) -> Vec<String> {
+    locale
+        .join(vec![MARK.to_string(); len])
+        .split(MARK)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns the counts that may be written in the `text`, every number in it
+/// along with one and two, which may be spelled out
+fn counts(text: &str, format: NumberFormat) -> Vec<i64> {
+    let digit = |c: char| {
+        if c.is_ascii_digit() {
+            c.to_digit(10)
+        } else {
+            (c as u32)
+                .checked_sub(format.zero as u32)
+                .filter(|&digit| digit < 10)
+        }
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut counts = vec![1, 2];
+    let mut idx = 0;
+    while idx < chars.len() {
+        let mut number = String::new();
+        while let Some(&c) = chars.get(idx) {
+            let grouped = !number.is_empty()
+                && Some(c) == format.group_separator
+                && chars.get(idx + 1).copied().and_then(digit).is_some();
+            match digit(c) {
+                Some(digit) => number.push(std::char::from_digit(digit, 10).unwrap_or('0')),
+                None if grouped => {}
+                None => break,
+            }
+            idx += 1;
+        }
+        if number.is_empty() {
+            idx += 1;
+        } else if let Ok(count) = number.parse() {
+            counts.push(count);
+        }
+    }
+    counts.sort_unstable();
+    counts.dedup();
+    counts
+}
+
+/// Returns how many bytes of the `text` match the whole `pattern`, letter case and
+/// the kinds of spaces aside
+fn matches(
+    mut text: impl Iterator<Item = char>,
+    pattern: impl Iterator<Item = char>,
+) -> Option<usize> {
+    let mut len = 0;
+    for expected in pattern {
+        let c = text.next()?;
+        let same = c == expected
+            || c.is_whitespace() && expected.is_whitespace()
+            || c.to_lowercase().eq(expected.to_lowercase());
+        if !same {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+    Some(len)
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod turkish {
+    use chrono::Duration;
+    use chrono_humanize::locale::Turkish;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Turkish,
+        now: Duration::zero(), Rough, Present, "şimdi",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3 saat önce",
+        in_an_hour: Duration::hours(1), Rough, Future, "bir saat sonra",
+        list: Duration::days(45), Precise, Past, "1 ay, 2 hafta ve 1 gün önce",
+    }
+}
+
+#[cfg(test)]
+mod finnish {
+    use chrono::Duration;
+    use chrono_humanize::locale::Finnish;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Finnish,
+        now: Duration::zero(), Rough, Present, "nyt",
+        in_three_hours: Duration::hours(3), Rough, Future, "3 tunnin päästä",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3 tuntia sitten",
+        hour_ago: Duration::hours(-1), Rough, Past, "tunti sitten",
+        in_an_hour: Duration::hours(1), Rough, Future, "tunnin päästä",
+        one_hour: Duration::hours(1), Precise, Present, "1 tunti",
+        list_past: Duration::days(45), Precise, Past, "1 kuukausi, 2 viikkoa ja 1 päivä sitten",
+        list_future: Duration::days(45), Precise, Future, "1 kuukauden, 2 viikon ja 1 päivän päästä",
+    }
+}
+
+#[cfg(test)]
+mod hungarian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Hungarian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Hungarian,
+        now: Duration::zero(), Rough, Present, "most",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3 órával ezelőtt",
+        in_three_hours: Duration::hours(3), Rough, Future, "3 óra múlva",
+        three_hours: Duration::hours(3), Rough, Present, "3 óra",
+        minutes_ago: Duration::minutes(-5), Rough, Past, "5 perccel ezelőtt",
+        days_ago: Duration::days(-3), Rough, Past, "3 nappal ezelőtt",
+        weeks_ago: Duration::weeks(-2), Rough, Past, "2 héttel ezelőtt",
+        years_ago: Duration::days(-730), Rough, Past, "2 évvel ezelőtt",
+        hour_ago: Duration::hours(-1), Rough, Past, "egy órával ezelőtt",
+        list: Duration::days(45), Precise, Past, "1 hónap, 2 hét és 1 nappal ezelőtt",
+        millis: Duration::milliseconds(-3), Precise, Past, "3 milliszekundummal ezelőtt",
+    }
+}
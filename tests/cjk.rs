@@ -0,0 +1,60 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod simplified_chinese {
+    use chrono::Duration;
+    use chrono_humanize::locale::SimplifiedChinese;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { SimplifiedChinese,
+        now: Duration::zero(), Rough, Present, "现在",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3小时前",
+        in_two_days: Duration::days(2), Rough, Future, "2天后",
+        list: Duration::days(45), Precise, Past, "1个月2周1天前",
+    }
+}
+
+#[cfg(test)]
+mod traditional_chinese {
+    use chrono::Duration;
+    use chrono_humanize::locale::TraditionalChinese;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { TraditionalChinese,
+        now: Duration::zero(), Rough, Present, "現在",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3小時前",
+        in_two_days: Duration::days(2), Rough, Future, "2天後",
+        list: Duration::days(45), Precise, Future, "1個月2週1天後",
+    }
+}
+
+#[cfg(test)]
+mod japanese {
+    use chrono::Duration;
+    use chrono_humanize::locale::Japanese;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Japanese,
+        now: Duration::zero(), Rough, Present, "今",
+        in_three_hours: Duration::hours(3), Rough, Future, "3時間後",
+        minute_ago: Duration::minutes(-1), Rough, Past, "1分前",
+        list: Duration::days(45), Precise, Present, "1か月2週間1日",
+    }
+}
+
+#[cfg(test)]
+mod korean {
+    use chrono::Duration;
+    use chrono_humanize::locale::Korean;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Korean,
+        now: Duration::zero(), Rough, Present, "지금",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "3시간 전",
+        in_a_week: Duration::weeks(1), Rough, Future, "1주 후",
+        list: Duration::days(45), Precise, Past, "1개월 2주 1일 전",
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "cldr")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod swedish {
+    use chrono::Duration;
+    use chrono_humanize::locale::CldrLocale;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { CldrLocale::new("sv").unwrap(),
+        now: Duration::zero(), Rough, Present, "nu",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "för 3 timmar sedan",
+        in_a_day: Duration::days(1), Rough, Future, "om 1 dag",
+        list: Duration::minutes(-62), Precise, Past, "för 1 timme och 2 minuter sedan",
+        present: Duration::days(45), Precise, Present, "1 månad, 2 veckor och 1 dygn",
+    }
+}
+
+#[cfg(test)]
+mod prefix {
+    use chrono::Duration;
+    use chrono_humanize::locale::CldrLocale;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { CldrLocale::new("fr").unwrap(),
+        three_hours_ago: Duration::hours(-3), Rough, Past, "il y a 3 heures",
+        list: Duration::minutes(-62), Precise, Past, "il y a 1 heure et 2 minutes",
+    }
+}
+
+#[cfg(test)]
+mod suffix {
+    use chrono::Duration;
+    use chrono_humanize::locale::CldrLocale;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { CldrLocale::new("pl").unwrap(),
+        hour_ago: Duration::hours(-1), Rough, Past, "1 godzinę temu",
+        few_hours_ago: Duration::hours(-3), Rough, Past, "3 godziny temu",
+        many_hours_ago: Duration::hours(-5), Rough, Past, "5 godzin temu",
+        list: Duration::minutes(-62), Precise, Past, "1 godzina i 2 minuty temu",
+    }
+}
+
+#[cfg(test)]
+mod numbers {
+    use chrono::Duration;
+    use chrono_humanize::locale::CldrLocale;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { CldrLocale::new("fa").unwrap(),
+        native_digits: Duration::days(-365 * 1234), Rough, Past, "۱٬۲۳۴ سال پیش",
+    }
+
+    mod german {
+        use super::*;
+
+        locale_test! { CldrLocale::new("de").unwrap(),
+            grouping: Duration::days(365 * 1234), Rough, Future, "in 1.234 Jahren",
+        }
+    }
+}
+
+#[cfg(test)]
+mod data {
+    use chrono::Duration;
+    use chrono_humanize::locale::{CldrLocale, Coverage, PluralCategory, Registry};
+    use chrono_humanize::{Accuracy, HumanTime, Locale, Tense};
+
+    #[test]
+    fn coverage() {
+        assert!(CldrLocale::all().count() > 100);
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!("sr-Latn", CldrLocale::new("SR_latn").unwrap().tag());
+        assert!(CldrLocale::new("tlh").is_none());
+    }
+
+    #[test]
+    fn plural_rules() {
+        let welsh = CldrLocale::new("cy").unwrap();
+        let categories = [0, 1, 2, 3, 6, 7]
+            .iter()
+            .map(|&count| welsh.plural(count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                PluralCategory::Zero,
+                PluralCategory::One,
+                PluralCategory::Two,
+                PluralCategory::Few,
+                PluralCategory::Many,
+                PluralCategory::Other
+            ],
+            categories
+        );
+    }
+
+    #[test]
+    fn registry() {
+        let ht = HumanTime::from(Duration::hours(-3));
+        let registry = Registry::builtin();
+        let text = ht.to_text(registry.resolve("sv-FI"), Accuracy::Rough, Tense::Past);
+        assert_eq!("för 3 timmar sedan", text);
+    }
+
+    #[test]
+    fn handwritten_take_precedence() {
+        let ht = HumanTime::from(Duration::hours(-1));
+        let text = ht.to_text(
+            Registry::builtin().resolve("en"),
+            Accuracy::Rough,
+            Tense::Past,
+        );
+        assert_eq!("an hour ago", text);
+    }
+
+    #[test]
+    fn eternity() {
+        let swedish = Coverage::of(&CldrLocale::new("sv").unwrap());
+        let english: Vec<_> = swedish.english().map(|message| message.key()).collect();
+        assert!(english.contains(&"eternity"), "{:?}", english);
+    }
+}
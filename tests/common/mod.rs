@@ -0,0 +1,12 @@
+/// Defines a test for every named duration, checking its text in the `$locale`
+/// with the given accuracy and tense
+macro_rules! locale_test {
+    ($locale:expr, $($name:ident: $duration:expr, $accuracy:expr, $tense:expr, $text:expr,)+) => {
+        $(#[test]
+        fn $name() {
+            let ht = HumanTime::from($duration);
+            let text = ht.to_text(&$locale, $accuracy, $tense);
+            assert_eq!($text, text);
+        })+
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod pseudo {
+    use chrono::Duration;
+    use chrono_humanize::locale::{English, PseudoLocale};
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    fn pseudo() -> PseudoLocale {
+        PseudoLocale::new(English)
+    }
+
+    #[test]
+    fn rough() {
+        let ht = HumanTime::from(Duration::minutes(-1));
+        assert_eq!(
+            "[á ɱîñûţé áĝô······]",
+            format!("{}", ht.localized(&pseudo()))
+        );
+        let ht = HumanTime::from(Duration::zero());
+        assert_eq!("[ñôŵ·]", format!("{}", ht.localized(&pseudo())));
+    }
+
+    #[test]
+    fn precise() {
+        let ht = HumanTime::from(Duration::days(8) + Duration::seconds(5));
+        let text = ht.to_text(&pseudo(), Accuracy::Precise, Tense::Future);
+        assert_eq!("[îñ 1 ŵééķ, 1 ðáý áñð 5 šéçôñðš···············]", text);
+    }
+
+    #[test]
+    fn registry() {
+        let ht = HumanTime::from(Duration::hours(3));
+        let text = ht.to_text_for("qps-ploc", Accuracy::Rough, Tense::Future);
+        assert_eq!("[îñ 3 ĥôûŕš·····]", text);
+        assert_eq!(
+            text,
+            ht.to_text_for("en-XA", Accuracy::Rough, Tense::Future)
+        );
+    }
+}
+
+#[cfg(test)]
+mod coverage {
+    use std::borrow::Cow;
+
+    use chrono_humanize::locale::{
+        Context, Coverage, English, Locale, PluralCategory, Status, Unit,
+    };
+
+    /// Locale that has not been translated past the tenses
+    struct Partial;
+
+    impl Locale for Partial {
+        fn plural(&self, count: i64) -> PluralCategory {
+            English.plural(count)
+        }
+
+        fn unit(&self, unit: Unit, plural: PluralCategory, context: Context) -> Cow<'_, str> {
+            English.unit(unit, plural, context)
+        }
+
+        fn now(&self) -> Cow<'_, str> {
+            "".into()
+        }
+
+        fn eternity(&self) -> Cow<'_, str> {
+            "toujours".into()
+        }
+
+        fn join(&self, parts: Vec<String>) -> String {
+            English.join(parts)
+        }
+
+        fn past(&self, text: String) -> String {
+            format!("il y a {}", text)
+        }
+
+        fn future(&self, text: String) -> String {
+            format!("dans {}", text)
+        }
+    }
+
+    fn keys<'a>(
+        messages: impl Iterator<Item = &'a chrono_humanize::locale::Message>,
+    ) -> Vec<&'a str> {
+        messages.map(|message| message.key()).collect()
+    }
+
+    #[test]
+    fn keys_of_english() {
+        let coverage = Coverage::of(&English);
+        let all = keys(coverage.messages().iter());
+        assert_eq!(
+            vec!["now", "eternity", "past", "future", "list.2", "list.3"],
+            all[..6]
+        );
+        assert_eq!(["period.nanos.one", "period.nanos.other"], all[6..8]);
+        assert!(all.contains(&"rough.hours"));
+        assert!(!all.contains(&"rough.seconds"));
+        assert!(all.contains(&"period.days.other.past"));
+        assert!(all.contains(&"rough.months.future"));
+        assert_eq!(6 + 3 * (10 * 2 + 6), all.len());
+    }
+
+    #[test]
+    fn missing_and_english() {
+        let coverage = Coverage::of(&Partial);
+        assert_eq!(vec!["now"], keys(coverage.missing()));
+        assert!(keys(coverage.english()).contains(&"period.hours.other"));
+        assert!(keys(coverage.english()).contains(&"period.hours.other.past"));
+        assert!(!keys(coverage.english()).contains(&"past"));
+        assert!(!coverage.is_complete());
+
+        let past = &coverage.messages()[2];
+        assert_eq!(
+            ("il y a {0}", Status::Translated),
+            (past.text(), past.status())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-locales")]
+    fn builtin() {
+        use chrono_humanize::locale::Registry;
+
+        let registry = Registry::builtin();
+        for tag in registry.tags() {
+            let coverage = Coverage::of(registry.get(tag).unwrap());
+            assert_eq!(0, coverage.missing().count(), "{}", tag);
+        }
+        let russian = Coverage::of(registry.get("ru").unwrap());
+        let english = keys(russian.english());
+        assert!(english.is_empty(), "{:?}", english);
+    }
+
+    #[test]
+    #[cfg(feature = "locale-de")]
+    fn symbols() {
+        use chrono_humanize::locale::German;
+
+        let german = Coverage::of(&German);
+        let millis = german
+            .messages()
+            .iter()
+            .find(|message| message.key() == "period.millis.other")
+            .unwrap();
+        assert_eq!(("0 ms", Status::English), (millis.text(), millis.status()));
+        // "in {0}" and the periods written with the same symbols as in English
+        let english = keys(german.english());
+        assert_eq!("future", english[0]);
+        let symbols = ["period.nanos.", "period.micros.", "period.millis."];
+        assert!(english[1..]
+            .iter()
+            .all(|key| symbols.iter().any(|symbol| key.starts_with(symbol))));
+        assert_eq!(1 + 3 * 2 * 3, english.len());
+        assert!(german.is_complete());
+    }
+}
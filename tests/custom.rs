@@ -0,0 +1,327 @@
+#![cfg(feature = "custom")]
+
+use chrono_humanize::locale::CustomLocale;
+
+const CASUAL: &str = r#"
+now = "just now"
+past = "{0} back"
+future = "{0} from now"
+
+[units]
+minutes = { one = "min", other = "mins" }
+hours = { one = "hr", other = "hrs" }
+days = { other = "{0}-day stretch" }
+
+[rough]
+hours = "an hr"
+
+[list]
+last = " & "
+
+[thresholds]
+now = 30
+minutes = 300
+
+[number]
+group_separator = "'"
+"#;
+
+const RUSSIAN: &str = r#"{
+    "past": "{0} назад",
+    "future": "через {0}",
+    "plural": {
+        "one": "v = 0 and i % 10 = 1 and i % 100 != 11",
+        "few": "v = 0 and i % 10 = 2..4 and i % 100 != 12..14",
+        "many": "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"
+    },
+    "units": {
+        "hours": { "one": "час", "few": "часа", "many": "часов", "other": "часа" }
+    }
+}"#;
+
+#[cfg(test)]
+mod toml {
+    use super::{CustomLocale, CASUAL};
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    fn text(duration: Duration) -> String {
+        let casual = CustomLocale::from_toml(CASUAL).unwrap();
+        format!("{}", HumanTime::from(duration).localized(&casual))
+    }
+
+    fn precise(duration: Duration) -> String {
+        let casual = CustomLocale::from_toml(CASUAL).unwrap();
+        format!("{:#}", HumanTime::from(duration).localized(&casual))
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!("3 hrs back", text(Duration::hours(-3)));
+        assert_eq!("an hr from now", text(Duration::hours(1)));
+        assert_eq!("2-day stretch back", text(Duration::days(-2)));
+    }
+
+    #[test]
+    fn fallback() {
+        assert_eq!("a week from now", text(Duration::weeks(1)));
+        assert_eq!("2 weeks back", text(Duration::weeks(-2)));
+    }
+
+    #[test]
+    fn list() {
+        let duration = Duration::hours(2) + Duration::minutes(1) + Duration::seconds(5);
+        assert_eq!("2 hrs, 1 min & 5 seconds from now", precise(duration));
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!("1'234 years back", text(Duration::days(-365 * 1234)));
+    }
+
+    #[test]
+    fn thresholds() {
+        assert_eq!("just now", text(Duration::seconds(-25)));
+        assert_eq!("45 seconds back", text(Duration::seconds(-45)));
+        assert_eq!("1 min from now", text(Duration::seconds(200)));
+        assert_eq!("6 mins back", text(Duration::minutes(-6)));
+    }
+}
+
+#[cfg(test)]
+mod json {
+    use super::{CustomLocale, RUSSIAN};
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn plural_rules() {
+        let russian = CustomLocale::from_json(RUSSIAN).unwrap();
+        let text = |hours| {
+            format!(
+                "{}",
+                HumanTime::from(Duration::hours(hours)).localized(&russian)
+            )
+        };
+        assert_eq!("через 21 час", text(21));
+        assert_eq!("3 часа назад", text(-3));
+        assert_eq!("через 5 часов", text(5));
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use super::CustomLocale;
+
+    #[test]
+    fn toml() {
+        let err = CustomLocale::from_toml("now = \"now\"\nnever = \"never\"\n").unwrap_err();
+        assert_eq!("invalid TOML", err.message());
+        assert!(err.to_string().contains("unknown field `never`"), "{}", err);
+        assert_eq!(12..17, err.span());
+
+        let err = CustomLocale::from_toml("units.hours = { one = \"hr\" }").unwrap_err();
+        assert!(err.to_string().contains("missing field `other`"), "{}", err);
+    }
+
+    #[test]
+    fn json() {
+        let err = CustomLocale::from_json("{\n  \"now\": 3\n}").unwrap_err();
+        assert_eq!(
+            (
+                "invalid JSON",
+                Some("invalid type: integer `3`, expected a string")
+            ),
+            (err.message(), err.detail())
+        );
+        assert_eq!(11..11, err.span());
+    }
+
+    #[test]
+    fn plural_rule() {
+        let err = CustomLocale::from_toml("plural.one = \"n % 0 = 1\"").unwrap_err();
+        assert_eq!("modulo by zero", err.message());
+        assert_eq!(18..19, err.span());
+    }
+}
+
+#[cfg(test)]
+mod moment {
+    use super::CustomLocale;
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    const DAYJS: &str = r#"{
+        "name": "en",
+        "relativeTime": {
+            "future": "in %s", "past": "%s ago", "s": "a few seconds",
+            "m": "a minute", "mm": "%d minutes", "h": "an hour", "hh": "%d hours",
+            "d": "a day", "dd": "%d days", "M": "a month", "MM": "%d months",
+            "y": "a year", "yy": "%d years"
+        }
+    }"#;
+
+    fn text(locale: &CustomLocale, seconds: i64) -> String {
+        format!(
+            "{}",
+            HumanTime::from(Duration::seconds(seconds)).localized(locale)
+        )
+    }
+
+    #[test]
+    fn thresholds() {
+        let english = CustomLocale::from_moment(DAYJS).unwrap();
+        let cases = [
+            (0, "a few seconds ago"),
+            (-44, "a few seconds ago"),
+            (45, "in a minute"),
+            (-89, "a minute ago"),
+            (90, "in 2 minutes"),
+            (44 * 60, "in 44 minutes"),
+            (45 * 60, "in an hour"),
+            (21 * 3600, "in 21 hours"),
+            (22 * 3600, "in a day"),
+            (25 * 86400, "in 25 days"),
+            (26 * 86400, "in a month"),
+            (-45 * 86400, "a month ago"),
+            (-46 * 86400, "2 months ago"),
+            (-319 * 86400, "10 months ago"),
+            (-320 * 86400, "a year ago"),
+            (-548 * 86400, "2 years ago"),
+        ];
+        for &(seconds, expected) in &cases {
+            assert_eq!(expected, text(&english, seconds), "{} seconds", seconds);
+        }
+    }
+
+    // What moment.js `humanize(true)` and dayjs `fromNow()` print on either side of the limits
+    #[test]
+    fn limits() {
+        let english = CustomLocale::from_moment(DAYJS).unwrap();
+        let cases = [
+            (149, "in 2 minutes"),
+            (150, "in 3 minutes"),
+            (2669, "in 44 minutes"),
+            (2670, "in an hour"),
+            (5399, "in an hour"),
+            (5400, "in 2 hours"),
+            (-8940, "2 hours ago"),
+            (-9600, "3 hours ago"),
+            (77399, "in 21 hours"),
+            (77400, "in a day"),
+            (129_599, "in a day"),
+            (129_600, "in 2 days"),
+            (907_199, "in 10 days"),
+            (907_200, "in 11 days"),
+            (2_203_199, "in 25 days"),
+            (2_203_200, "in a month"),
+            (3_944_618, "in a month"),
+            (3_944_619, "in 2 months"),
+            (-27_612_332, "10 months ago"),
+            (-27_612_333, "a year ago"),
+            (-47_335_427, "a year ago"),
+            (-47_335_428, "2 years ago"),
+        ];
+        for &(seconds, expected) in &cases {
+            assert_eq!(expected, text(&english, seconds), "{} seconds", seconds);
+        }
+        let ht = HumanTime::from(Duration::milliseconds(44_499));
+        assert_eq!("in a few seconds", format!("{}", ht.localized(&english)));
+        let ht = HumanTime::from(Duration::milliseconds(44_500));
+        assert_eq!("in a minute", format!("{}", ht.localized(&english)));
+    }
+
+    #[test]
+    fn dayjs_thresholds() {
+        let source = r#"{
+            "relativeTime": {
+                "future": "in %s", "past": "%s ago", "s": "a few seconds",
+                "m": "a minute", "mm": "%d minutes", "h": "an hour", "hh": "%d hours",
+                "d": "a day", "dd": "%d days", "M": "a month", "MM": "%d months",
+                "y": "a year", "yy": "%d years"
+            },
+            "thresholds": [
+                { "l": "s", "r": 1, "d": "second" },
+                { "l": "m", "r": 1 },
+                { "l": "mm", "r": 59, "d": "minute" },
+                { "l": "h", "r": 1 },
+                { "l": "hh", "r": 23, "d": "hour" },
+                { "l": "d", "r": 1 },
+                { "l": "dd", "r": 29, "d": "day" },
+                { "l": "M", "r": 1 },
+                { "l": "MM", "r": 11, "d": "month" },
+                { "l": "y" },
+                { "l": "yy", "d": "year" }
+            ]
+        }"#;
+        let locale = CustomLocale::from_moment(source).unwrap();
+        let cases = [
+            (1, "in a few seconds"),
+            (2, "in a minute"),
+            (3569, "in 59 minutes"),
+            (3570, "in an hour"),
+            (84599, "in 23 hours"),
+            (84600, "in a day"),
+            (2_548_799, "in 29 days"),
+            (2_548_800, "in a month"),
+            (30_242_078, "in 11 months"),
+            (30_242_079, "in a year"),
+            (157_784_760, "in a year"),
+        ];
+        for &(seconds, expected) in &cases {
+            assert_eq!(expected, text(&locale, seconds), "{} seconds", seconds);
+        }
+
+        let err =
+            CustomLocale::from_moment(r#"{ "relativeTime": {}, "thresholds": 5 }"#).unwrap_err();
+        assert_eq!("invalid dayjs thresholds", err.message());
+    }
+
+    #[test]
+    fn precise() {
+        let english = CustomLocale::from_moment(DAYJS).unwrap();
+        let ht = HumanTime::from(Duration::days(-1) - Duration::hours(1));
+        assert_eq!(
+            "a day and an hour ago",
+            format!("{:#}", ht.localized(&english))
+        );
+        let ht = HumanTime::from(Duration::days(3) + Duration::minutes(1));
+        assert_eq!(
+            "in 3 days and a minute",
+            format!("{:#}", ht.localized(&english))
+        );
+    }
+
+    #[test]
+    fn weeks() {
+        let source = r#"{ "w": "a week", "ww": "%d weeks", "dd": "%d days" }"#;
+        let locale = CustomLocale::from_moment(source).unwrap();
+        assert_eq!("in a week", text(&locale, 7 * 86400));
+        assert_eq!("in 2 weeks", text(&locale, 14 * 86400));
+        let locale = CustomLocale::from_moment(r#"{ "dd": "%d days" }"#).unwrap();
+        assert_eq!("in 14 days", text(&locale, 14 * 86400));
+    }
+
+    #[test]
+    fn to_toml() {
+        let english = CustomLocale::from_moment(DAYJS).unwrap();
+        let reloaded = CustomLocale::from_toml(&english.to_toml()).unwrap();
+        for &seconds in &[0, 30, -60, 3600, -5 * 86400, 40 * 86400, -800 * 86400] {
+            assert_eq!(text(&english, seconds), text(&reloaded, seconds));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = CustomLocale::from_moment(r#"{ "mm": 5 }"#).unwrap_err();
+        assert_eq!(
+            (
+                "invalid moment.js locale",
+                Some("invalid type: integer `5`, expected a string")
+            ),
+            (err.message(), err.detail())
+        );
+        let err = CustomLocale::from_moment(r#"{ "mm": "%d minutes", }"#).unwrap_err();
+        assert_eq!(22..22, err.span());
+    }
+}
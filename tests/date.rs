@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod relative {
+    use chrono::{DateTime, FixedOffset, TimeZone};
+    use chrono_humanize::parse_date;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<FixedOffset> {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        tz.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    /// Tuesday, December 31, 2024 at 9:30
+    fn parse(text: &str) -> DateTime<FixedOffset> {
+        parse_date(text, &at(2024, 12, 31, 9, 30)).unwrap()
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(at(2024, 12, 31, 0, 0), parse("today"));
+        assert_eq!(at(2025, 1, 1, 0, 0), parse("Tomorrow"));
+        assert_eq!(at(2024, 12, 30, 0, 0), parse("yesterday"));
+        assert_eq!(at(2025, 1, 1, 0, 0), parse("2025-01-01"));
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(at(2025, 1, 7, 0, 0), parse("next tuesday"));
+        assert_eq!(at(2025, 1, 7, 0, 0), parse("tuesday"));
+        assert_eq!(at(2025, 1, 3, 0, 0), parse("fri"));
+        assert_eq!(at(2024, 12, 24, 0, 0), parse("last tuesday"));
+        assert_eq!(at(2024, 12, 30, 0, 0), parse("last monday"));
+        assert_eq!(at(2024, 12, 30, 0, 0), parse("this monday"));
+        assert_eq!(at(2025, 1, 5, 0, 0), parse("this sunday"));
+    }
+
+    #[test]
+    fn calendar() {
+        assert_eq!(at(2025, 1, 7, 9, 30), parse("next week"));
+        assert_eq!(at(2024, 11, 30, 9, 30), parse("last month"));
+        assert_eq!(at(2025, 12, 31, 9, 30), parse("next year"));
+        assert_eq!(at(2024, 12, 31, 9, 30), parse("this month"));
+        assert_eq!(at(2025, 2, 28, 9, 30), parse("2 months after now"));
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(at(2025, 1, 1, 17, 0), parse("tomorrow at 5pm"));
+        assert_eq!(at(2024, 12, 31, 17, 45), parse("17:45"));
+        assert_eq!(at(2024, 12, 31, 0, 30), parse("at 12:30 AM"));
+        assert_eq!(at(2024, 12, 31, 12, 0), parse("noon"));
+        assert_eq!(at(2025, 1, 7, 0, 0), parse("next tuesday at midnight"));
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(at(2024, 12, 31, 9, 30), parse("now"));
+        assert_eq!(at(2024, 12, 31, 12, 30), parse("in 3 hours"));
+        assert_eq!(at(2024, 12, 29, 5, 30), parse("2 days and 4 hours ago"));
+        assert_eq!(at(2024, 12, 29, 0, 0), parse("3 days before 2025-01-01"));
+        assert_eq!(at(2025, 1, 8, 8, 0), parse("a week after tomorrow at 8am"));
+        assert_eq!(
+            at(2025, 1, 9, 0, 0),
+            parse("1 day after a week after tomorrow")
+        );
+    }
+}
+
+#[cfg(test)]
+mod humanize {
+    use chrono::{FixedOffset, TimeZone};
+    use chrono_humanize::{parse_date, HumanTime};
+
+    #[test]
+    fn feeds_humantime() {
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let reference = tz.with_ymd_and_hms(2025, 4, 1, 12, 0, 0).unwrap();
+        let date = parse_date("in 3 weeks", &reference).unwrap();
+        assert_eq!("in 3 weeks", HumanTime::from(date - reference).to_string());
+        let date = parse_date("last month", &reference).unwrap();
+        assert_eq!("a month ago", HumanTime::from(date - reference).to_string());
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use chrono::{TimeZone, Utc};
+    use chrono_humanize::parse_date;
+
+    fn error(text: &str) -> String {
+        let reference = Utc.with_ymd_and_hms(2024, 12, 31, 9, 30, 0).unwrap();
+        parse_date(text, &reference).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("expected date or time at 0..0", error(""));
+        assert_eq!("expected date or time at 0..8", error("sometime"));
+        assert_eq!(
+            "expected weekday, week, month or year at 5..14",
+            error("next fortnight")
+        );
+        assert_eq!(
+            "expected 'ago', 'before' or 'after' at 8..13",
+            error("3 hours later")
+        );
+        assert_eq!("expected date at 7..7", error("2025-01"));
+        assert_eq!("expected minutes at 2..3", error("5:3"));
+        assert_eq!("unexpected text at 6..7", error("today 5pm"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!("invalid date at 0..10", error("2025-02-30"));
+        assert_eq!("invalid time at 0..4", error("13pm"));
+        assert_eq!("invalid time at 9..14", error("today at 24:00"));
+        assert_eq!("date out of range at 0..15", error("999999999 years ago"));
+    }
+}
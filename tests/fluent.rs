@@ -0,0 +1,109 @@
+#![cfg(feature = "fluent")]
+
+use chrono_humanize::locale::FluentLocale;
+
+const POLISH: &str = r#"
+humantime-now = teraz
+humantime-period = { $accuracy ->
+    [rough] { $count ->
+        [1] { $unit ->
+            [hours] godzinę
+           *[other] { $count } { $unit }
+        }
+       *[other] { $count } { $unit }
+    }
+   *[precise] { $count } { $unit }
+}
+    .unused = attribute
+humantime-list = { $head }, { $tail }
+humantime-list-end = { $head } i { $tail }
+humantime-past = { $text } temu
+humantime-future = za { $text }
+"#;
+
+fn polish() -> FluentLocale {
+    FluentLocale::from_ftl("pl", POLISH).unwrap()
+}
+
+#[cfg(test)]
+mod messages {
+    use super::polish;
+    use chrono::Duration;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    #[test]
+    fn now() {
+        let ht = HumanTime::from(Duration::zero());
+        assert_eq!("teraz", format!("{}", ht.localized(&polish())));
+    }
+
+    #[test]
+    fn rough() {
+        let ht = HumanTime::from(Duration::hours(-1));
+        assert_eq!("godzinę temu", format!("{}", ht.localized(&polish())));
+    }
+
+    #[test]
+    fn precise() {
+        let ht = HumanTime::from(Duration::days(45) + Duration::minutes(2));
+        let text = format!("{:#}", ht.localized(&polish()));
+        assert_eq!("za 1 months, 2 weeks, 1 days i 2 minutes", text);
+    }
+
+    #[test]
+    fn same_as_to_text() {
+        let ht = HumanTime::from(Duration::hours(-3));
+        let text = ht.to_text(&polish(), Accuracy::Rough, Tense::Past);
+        assert_eq!(format!("{}", ht.localized(&polish())), text);
+    }
+}
+
+#[cfg(test)]
+mod fallback {
+    use chrono::Duration;
+    use chrono_humanize::locale::{FluentLocale, PluralCategory};
+    use chrono_humanize::{HumanTime, Locale};
+
+    #[test]
+    fn english_for_missing_messages() {
+        let locale = FluentLocale::from_ftl("en", "humantime-past = { $text } back").unwrap();
+        let ht = HumanTime::from(Duration::hours(-3));
+        assert_eq!("3 hours back", format!("{}", ht.localized(&locale)));
+        let ht = HumanTime::from(Duration::hours(3));
+        assert_eq!("in 3 hours", format!("{}", ht.localized(&locale)));
+    }
+
+    #[test]
+    fn period_from_units() {
+        let ftl = r#"
+humantime-unit = { $unit ->
+    [hours] heures
+   *[other] { $unit }
+}
+humantime-past = il y a { $text }
+"#;
+        let locale = FluentLocale::from_ftl("fr", ftl).unwrap();
+        let ht = HumanTime::from(Duration::hours(-3));
+        assert_eq!("il y a 3 heures", format!("{}", ht.localized(&locale)));
+    }
+
+    #[test]
+    fn plural_rules_of_the_language() {
+        let locale = FluentLocale::from_ftl("pl", "").unwrap();
+        assert_eq!(PluralCategory::One, locale.plural(1));
+        assert_eq!(PluralCategory::Few, locale.plural(22));
+        assert_eq!(PluralCategory::Many, locale.plural(25));
+    }
+
+    #[test]
+    fn syntax_error() {
+        let error =
+            FluentLocale::from_ftl("en", "humantime-now = \nhumantime-past { $text }").unwrap_err();
+        assert_eq!("invalid Fluent syntax", error.message());
+    }
+
+    #[test]
+    fn invalid_tag() {
+        assert!(FluentLocale::from_ftl("not a tag", "").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod german {
+    use chrono::Duration;
+    use chrono_humanize::locale::German;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { German,
+        now: Duration::zero(), Rough, Present, "jetzt",
+        three_days_ago: Duration::days(-3), Rough, Past, "vor 3 Tagen",
+        in_three_days: Duration::days(3), Rough, Future, "in 3 Tagen",
+        three_days: Duration::days(3), Rough, Present, "3 Tage",
+        year_ago: Duration::days(-365), Rough, Past, "vor einem Jahr",
+        in_a_year: Duration::days(365), Rough, Future, "in einem Jahr",
+        a_year: Duration::days(365), Rough, Present, "ein Jahr",
+        hour_ago: Duration::hours(-1), Rough, Past, "vor einer Stunde",
+        an_hour: Duration::hours(1), Rough, Present, "eine Stunde",
+        one_year_ago: Duration::days(-365), Precise, Past, "vor 1 Jahr",
+        list: Duration::days(45), Precise, Past, "vor 1 Monat, 2 Wochen und 1 Tag",
+        months: Duration::days(65), Precise, Future, "in 2 Monaten und 5 Tagen",
+    }
+}
+
+#[cfg(test)]
+mod dutch {
+    use chrono::Duration;
+    use chrono_humanize::locale::Dutch;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Dutch,
+        now: Duration::zero(), Rough, Present, "nu",
+        three_days_ago: Duration::days(-3), Rough, Past, "3 dagen geleden",
+        in_three_hours: Duration::hours(3), Rough, Future, "over 3 uur",
+        year_ago: Duration::days(-365), Rough, Past, "een jaar geleden",
+        two_years: Duration::days(730), Rough, Present, "2 jaar",
+        list: Duration::days(45), Precise, Future, "over 1 maand, 2 weken en 1 dag",
+    }
+}
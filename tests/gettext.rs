@@ -0,0 +1,273 @@
+#![cfg(feature = "gettext")]
+
+use chrono_humanize::locale::{Catalog, GettextLocale};
+
+const POLISH: &str = r#"
+# Polish translation
+msgid ""
+msgstr ""
+"Language: pl\n"
+"Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && "
+"(n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+msgid "now"
+msgstr "teraz"
+
+msgid "an hour"
+msgstr "godzinę"
+
+msgid "%d minute"
+msgid_plural "%d minutes"
+msgstr[0] "%d minuta"
+msgstr[1] "%d minuty"
+msgstr[2] "%d minut"
+
+msgid "%d hour"
+msgid_plural "%d hours"
+msgstr[0] "%d godzina"
+msgstr[1] "%d godziny"
+msgstr[2] "%d godzin"
+
+#, fuzzy
+msgid "%d day"
+msgid_plural "%d days"
+msgstr[0] "%d dzień"
+msgstr[1] "%d dni"
+msgstr[2] "%d dni"
+
+msgid "%s and %s"
+msgstr "%s i %s"
+
+msgid "%s ago"
+msgstr "%s temu"
+
+msgid "in %s"
+msgstr "za %s"
+"#;
+
+fn polish() -> GettextLocale {
+    GettextLocale::new(Catalog::from_po(POLISH).unwrap())
+}
+
+/// Builds a little-endian `.mo` file out of the `messages`
+fn mo(messages: &[(&str, &str)]) -> Vec<u8> {
+    let mut messages = messages.to_vec();
+    messages.sort();
+    let count = messages.len() as u32;
+    let strings_start = 28 + 16 * count;
+
+    let mut header = vec![];
+    let mut tables = (vec![], vec![]);
+    let mut strings = vec![];
+    for (msgid, _) in &messages {
+        tables
+            .0
+            .push((msgid.len() as u32, strings_start + strings.len() as u32));
+        strings.extend_from_slice(msgid.as_bytes());
+        strings.push(0);
+    }
+    for (_, msgstr) in &messages {
+        tables
+            .1
+            .push((msgstr.len() as u32, strings_start + strings.len() as u32));
+        strings.extend_from_slice(msgstr.as_bytes());
+        strings.push(0);
+    }
+
+    for word in [0x9504_12de, 0, count, 28, 28 + 8 * count, 0, 0] {
+        header.extend_from_slice(&u32::to_le_bytes(word));
+    }
+    for (len, offset) in tables.0.into_iter().chain(tables.1) {
+        header.extend_from_slice(&len.to_le_bytes());
+        header.extend_from_slice(&offset.to_le_bytes());
+    }
+    header.extend(strings);
+    header
+}
+
+#[cfg(test)]
+mod po {
+    use super::polish;
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn now() {
+        let ht = HumanTime::from(Duration::zero());
+        assert_eq!("teraz", format!("{}", ht.localized(&polish())));
+    }
+
+    #[test]
+    fn rough() {
+        let ht = HumanTime::from(Duration::hours(-1));
+        assert_eq!("godzinę temu", format!("{}", ht.localized(&polish())));
+        let ht = HumanTime::from(Duration::minutes(22));
+        assert_eq!("za 22 minuty", format!("{}", ht.localized(&polish())));
+        let ht = HumanTime::from(Duration::minutes(-25));
+        assert_eq!("25 minut temu", format!("{}", ht.localized(&polish())));
+    }
+
+    #[test]
+    fn precise() {
+        let ht = HumanTime::from(Duration::hours(5) + Duration::minutes(1));
+        assert_eq!(
+            "za 5 godzin i 1 minuta",
+            format!("{:#}", ht.localized(&polish()))
+        );
+    }
+
+    #[test]
+    fn fallback() {
+        // The fuzzy entry is not used, the message stays in English
+        let ht = HumanTime::from(Duration::days(-2));
+        assert_eq!("2 days temu", format!("{}", ht.localized(&polish())));
+        let ht = HumanTime::from(Duration::weeks(3) + Duration::days(2));
+        assert_eq!(
+            "za 3 weeks i 2 days",
+            format!("{:#}", ht.localized(&polish()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod mo {
+    use super::mo;
+    use chrono::Duration;
+    use chrono_humanize::locale::{Catalog, GettextLocale};
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn messages() {
+        let catalog = Catalog::from_mo(&mo(&[
+            ("", "Plural-Forms: nplurals=2; plural=(n > 1);\n"),
+            ("%d hour\0%d hours", "%d heure\0%d heures"),
+            ("%s ago", "il y a %s"),
+            ("%s and %s", "%s et %s"),
+        ]))
+        .unwrap();
+        assert_eq!(2, catalog.plural_forms().nplurals());
+        assert_eq!("%d heure", catalog.ngettext("%d hour", "%d hours", 0));
+
+        let french = GettextLocale::new(catalog);
+        let ht = HumanTime::from(Duration::hours(-3));
+        assert_eq!("il y a 3 heures", format!("{}", ht.localized(&french)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "not a .mo file",
+            Catalog::from_mo(b"hello world!").unwrap_err().message()
+        );
+
+        let mut bytes = mo(&[("now", "maintenant")]);
+        bytes.truncate(40);
+        let err = Catalog::from_mo(&bytes).unwrap_err();
+        assert_eq!("string out of bounds", err.message());
+        assert_eq!(28..36, err.span());
+    }
+}
+
+#[cfg(test)]
+mod plural_forms {
+    use chrono_humanize::locale::{Catalog, PluralForms};
+
+    fn forms(text: &str) -> PluralForms {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn evaluate() {
+        let russian = forms("nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);");
+        let indices: Vec<_> = [1, 2, 5, 11, 21, 22, 111, 112]
+            .iter()
+            .map(|&n| russian.index(n))
+            .collect();
+        assert_eq!(vec![0, 1, 2, 2, 0, 1, 2, 2], indices);
+
+        let japanese = forms("nplurals=1; plural=0;");
+        assert_eq!(0, japanese.index(5));
+
+        let arabic = forms("nplurals=6; plural=n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5;");
+        let indices: Vec<_> = [0, 1, 2, 3, 11, 100]
+            .iter()
+            .map(|&n| arabic.index(n))
+            .collect();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], indices);
+
+        assert_eq!(1, forms("nplurals=2; plural=!(n == 1);").index(4));
+        assert_eq!(1, forms("nplurals=2; plural=n / 2 - 1 + 0 * 3;").index(4));
+    }
+
+    #[test]
+    fn out_of_range() {
+        // Indices beyond nplurals fall back to the first form
+        assert_eq!(0, forms("nplurals=2; plural=n;").index(7));
+        assert_eq!(0, forms("nplurals=2; plural=n % 0;").index(7));
+    }
+
+    #[test]
+    fn display() {
+        let english = PluralForms::default();
+        assert_eq!("nplurals=2; plural=(n != 1);", english.to_string());
+        assert_eq!(english.to_string(), forms(&english.to_string()).to_string());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str| {
+            let err = text.parse::<PluralForms>().unwrap_err();
+            (err.message(), err.span())
+        };
+        assert_eq!(
+            ("expected ':'", 30..31),
+            error("nplurals=2; plural=n == 1 ? 0 1")
+        );
+        assert_eq!(
+            ("expected ')'", 26..26),
+            error("nplurals=2; plural=(n != 1")
+        );
+        assert_eq!(
+            ("expected expression", 24..25),
+            error("nplurals=2; plural=n != ?")
+        );
+        assert_eq!(("invalid nplurals", 9..10), error("nplurals=x; plural=n"));
+        assert_eq!(("missing plural", 0..11), error("nplurals=2;"));
+
+        let nested = format!(
+            "nplurals=2; plural={}n{};",
+            "(".repeat(10_000),
+            ")".repeat(10_000)
+        );
+        assert_eq!(("expression nested too deeply", 83..84), error(&nested));
+        let negated = format!("nplurals=2; plural={}n;", "!".repeat(10_000));
+        assert_eq!(("expression nested too deeply", 83..84), error(&negated));
+
+        let po = "msgid \"\"\nmsgstr \"Plural-Forms: nplurals=2; plural=n ==;\\n\"\n";
+        let err = Catalog::from_po(po).unwrap_err();
+        assert_eq!(("expected expression", 0..58), (err.message(), err.span()));
+    }
+
+    #[test]
+    fn po_errors() {
+        let err = Catalog::from_po("msgid \"now\"\nmsgstr \"teraz\n").unwrap_err();
+        assert_eq!(
+            ("expected quoted string", 19..25),
+            (err.message(), err.span())
+        );
+        let err = Catalog::from_po("msgid \"now\"\nmsgstring \"teraz\"\n").unwrap_err();
+        assert_eq!(("unknown keyword", 12..21), (err.message(), err.span()));
+
+        let err =
+            Catalog::from_po("msgid \"a\"\nmsgstr[18446744073709551615] \"x\"\n").unwrap_err();
+        assert_eq!(
+            ("plural form index too large", 17..37),
+            (err.message(), err.span())
+        );
+        let err = Catalog::from_po("msgid \"a\"\nmsgstr[4000000000] \"x\"\n").unwrap_err();
+        assert_eq!(
+            ("plural form index too large", 17..27),
+            (err.message(), err.span())
+        );
+    }
+}
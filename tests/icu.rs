@@ -0,0 +1,134 @@
+#![cfg(feature = "icu4x")]
+
+use chrono_humanize::locale::IcuFormatter;
+
+fn formatter(tag: &str) -> IcuFormatter {
+    IcuFormatter::try_new(tag).unwrap()
+}
+
+#[cfg(test)]
+mod rough {
+    use super::formatter;
+    use chrono::Duration;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    fn text(tag: &str, duration: Duration, tense: Tense) -> String {
+        HumanTime::from(duration).to_text_icu(&formatter(tag), Accuracy::Rough, tense)
+    }
+
+    #[test]
+    fn relative() {
+        assert_eq!("3 hours ago", text("en", Duration::hours(-3), Tense::Past));
+        assert_eq!(
+            "vor 3 Stunden",
+            text("de", Duration::hours(-3), Tense::Past)
+        );
+        assert_eq!(
+            "через 5 часов",
+            text("ru", Duration::hours(5), Tense::Future)
+        );
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!("yesterday", text("en", Duration::days(-1), Tense::Past));
+        assert_eq!(
+            "le mois prochain",
+            text("fr", Duration::days(30), Tense::Future)
+        );
+    }
+
+    #[test]
+    fn now() {
+        assert_eq!("now", text("en", Duration::seconds(5), Tense::Present));
+        assert_eq!("jetzt", text("de", Duration::zero(), Tense::Past));
+    }
+
+    #[test]
+    fn present() {
+        assert_eq!("3 días", text("es", Duration::days(3), Tense::Present));
+    }
+}
+
+#[cfg(test)]
+mod precise {
+    use super::formatter;
+    use chrono::Duration;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    fn text(tag: &str, duration: Duration, tense: Tense) -> String {
+        HumanTime::from(duration).to_text_icu(&formatter(tag), Accuracy::Precise, tense)
+    }
+
+    #[test]
+    fn list() {
+        let duration = Duration::days(45);
+        assert_eq!(
+            "1 month, 2 weeks, 1 day",
+            text("en", duration, Tense::Present)
+        );
+        assert_eq!(
+            "in 1 month, 2 weeks, 1 day",
+            text("en", duration, Tense::Future)
+        );
+        assert_eq!(
+            "il y a 1\u{a0}mois, 2\u{a0}semaines et 1\u{a0}jour",
+            text("fr", -duration, Tense::Past)
+        );
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!("1 day ago", text("en", Duration::days(-1), Tense::Past));
+        assert_eq!(
+            "in 250 milliseconds",
+            text("en", Duration::milliseconds(250), Tense::Future)
+        );
+    }
+
+    #[test]
+    fn fallback() {
+        let duration = Duration::days(-3) - Duration::hours(4);
+        assert_eq!("3 Tage, 4 Stunden ago", text("de", duration, Tense::Past));
+        let duration = Duration::days(5) + Duration::hours(3);
+        assert_eq!(
+            "in 5 dnů a\u{a0}3 hodiny",
+            text("cs", duration, Tense::Future)
+        );
+    }
+}
+
+#[cfg(test)]
+mod fallback {
+    use super::formatter;
+
+    #[test]
+    fn english() {
+        assert_eq!("eternity", formatter("de").eternity());
+    }
+
+    #[test]
+    #[cfg(feature = "locale-de")]
+    fn locale() {
+        use chrono::Duration;
+        use chrono_humanize::locale::German;
+        use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+        let german = formatter("de").with_fallback(&German);
+        assert_eq!("Ewigkeit", german.eternity());
+        let ht = HumanTime::from(Duration::days(-3) - Duration::hours(4));
+        let text = ht.to_text_icu(&german, Accuracy::Precise, Tense::Past);
+        assert_eq!("vor 3 Tage, 4 Stunden", text);
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use chrono_humanize::locale::IcuFormatter;
+
+    #[test]
+    fn invalid_tag() {
+        let err = IcuFormatter::try_new("not a tag").unwrap_err();
+        assert_eq!("invalid language tag at 0..9", err.to_string());
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod hindi {
+    use chrono::Duration;
+    use chrono_humanize::locale::Hindi;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    mod ascii {
+        use super::*;
+
+        locale_test! { Hindi::default(),
+            now: Duration::zero(), Rough, Present, "अब",
+            three_hours_ago: Duration::hours(-3), Rough, Past, "3 घंटे पहले",
+            hour_ago: Duration::hours(-1), Rough, Past, "एक घंटे पहले",
+            in_an_hour: Duration::hours(1), Rough, Future, "एक घंटे में",
+            hour: Duration::hours(1), Rough, Present, "एक घंटा",
+            in_a_month: Duration::days(30), Rough, Future, "एक महीने में",
+            week_ago: Duration::weeks(-1), Precise, Past, "1 हफ़्ते पहले",
+            in_two_months: Duration::days(60), Rough, Future, "2 महीने में",
+            list: Duration::days(45), Precise, Past, "1 महीना, 2 हफ़्ते और 1 दिन पहले",
+        }
+    }
+
+    mod native {
+        use super::*;
+
+        locale_test! { Hindi::default().native_digits(true),
+            three_hours_ago: Duration::hours(-3), Rough, Past, "३ घंटे पहले",
+            twelve_hours: Duration::hours(12), Rough, Present, "१२ घंटे",
+            hundred_seconds: Duration::seconds(100), Precise, Future, "१ मिनट और ४० सेकंड में",
+        }
+    }
+}
+
+#[cfg(test)]
+mod bengali {
+    use chrono::Duration;
+    use chrono_humanize::locale::Bengali;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    mod ascii {
+        use super::*;
+
+        locale_test! { Bengali::default(),
+            now: Duration::zero(), Rough, Present, "এখন",
+            three_hours_ago: Duration::hours(-3), Rough, Past, "3 ঘন্টা আগে",
+            in_two_days: Duration::days(2), Rough, Future, "2 দিন পরে",
+        }
+    }
+
+    mod native {
+        use super::*;
+
+        locale_test! { Bengali::default().native_digits(true),
+            three_hours_ago: Duration::hours(-3), Rough, Past, "৩ ঘন্টা আগে",
+            list: Duration::days(45), Precise, Present, "১ মাস, ২ সপ্তাহ এবং ১ দিন",
+        }
+    }
+}
+
+#[cfg(test)]
+mod marathi {
+    use chrono::Duration;
+    use chrono_humanize::locale::Marathi;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    mod ascii {
+        use super::*;
+
+        locale_test! { Marathi::default(),
+            now: Duration::zero(), Rough, Present, "आता",
+            three_hours_ago: Duration::hours(-3), Rough, Past, "3 तासांपूर्वी",
+            in_three_hours: Duration::hours(3), Rough, Future, "3 तासांमध्ये",
+            three_hours: Duration::hours(3), Rough, Present, "3 तास",
+            list: Duration::days(45), Precise, Past, "1 महिना, 2 आठवडे आणि 1 दिवसापूर्वी",
+        }
+    }
+
+    mod native {
+        use super::*;
+
+        locale_test! { Marathi::default().native_digits(true),
+            three_hours_ago: Duration::hours(-3), Rough, Past, "३ तासांपूर्वी",
+        }
+    }
+}
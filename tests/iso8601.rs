@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod output {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    fn iso(duration: Duration) -> String {
+        HumanTime::from(duration).to_iso8601()
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!("PT0S", iso(Duration::zero()));
+        assert_eq!("PT6S", iso(Duration::seconds(6)));
+        assert_eq!("P1D", iso(Duration::days(1)));
+        assert_eq!("P17D", iso(Duration::weeks(2) + Duration::days(3)));
+        assert_eq!(
+            "P1Y2M3DT4H5M6.5S",
+            iso(Duration::days(365 + 60 + 3)
+                + Duration::hours(4)
+                + Duration::minutes(5)
+                + Duration::milliseconds(6500))
+        );
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!("PT0.25S", iso(Duration::milliseconds(250)));
+        assert_eq!("PT1.000001S", iso(Duration::microseconds(1_000_001)));
+        assert_eq!("PT0.000000007S", iso(Duration::nanoseconds(7)));
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!("-PT3H", iso(Duration::hours(-3)));
+        assert_eq!(
+            "-P1MT0.5S",
+            iso(-Duration::days(30) - Duration::milliseconds(500))
+        );
+    }
+}
+
+#[cfg(test)]
+mod input {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    fn parse(text: &str) -> Duration {
+        HumanTime::parse_iso8601(text).unwrap().into()
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(Duration::zero(), parse("PT0S"));
+        assert_eq!(Duration::days(365 * 2), parse("P2Y"));
+        assert_eq!(Duration::minutes(5), parse("PT5M"));
+        assert_eq!(Duration::days(30 * 5), parse("P5M"));
+        assert_eq!(Duration::weeks(2), parse("P2W"));
+        assert_eq!(Duration::days(17), parse("P2W3D"));
+        assert_eq!(
+            Duration::days(365 + 60 + 3)
+                + Duration::hours(4)
+                + Duration::minutes(5)
+                + Duration::milliseconds(6500),
+            parse("P1Y2M3DT4H5M6.5S")
+        );
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(Duration::hours(36), parse("P1.5D"));
+        assert_eq!(Duration::minutes(90), parse("PT1,5H"));
+        assert_eq!(Duration::nanoseconds(1), parse("PT0.000000001S"));
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(Duration::days(-1), parse("-P1D"));
+        assert_eq!(Duration::days(1), parse("+P1D"));
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn precise() {
+        let durations = [
+            Duration::zero(),
+            Duration::nanoseconds(-7),
+            Duration::milliseconds(1500),
+            Duration::minutes(-62),
+            Duration::days(45),
+            Duration::days(-800) - Duration::hours(5) - Duration::nanoseconds(1),
+            Duration::weeks(52 * 2000),
+        ];
+        for &duration in &durations {
+            let text = HumanTime::from(duration).to_iso8601();
+            let parsed = HumanTime::parse_iso8601(&text).unwrap();
+            assert_eq!(duration, Duration::from(parsed), "{}", text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use chrono_humanize::HumanTime;
+
+    fn error(text: &str) -> String {
+        HumanTime::parse_iso8601(text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("expected 'P' at 0..0", error(""));
+        assert_eq!("expected 'P' at 0..1", error("1D"));
+        assert_eq!("expected number at 1..1", error("P"));
+        assert_eq!("expected number at 4..4", error("P1DT"));
+        assert_eq!("expected number at 1..2", error("PD"));
+        assert_eq!("expected designator at 2..3", error("P1H"));
+        assert_eq!("expected designator at 3..3", error("PT1"));
+        assert_eq!("component out of order at 4..5", error("P1D2Y"));
+        assert_eq!("component out of order at 4..5", error("P1D1D"));
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(
+            "only the last component may have a fraction at 1..4",
+            error("P1.5DT2H")
+        );
+        assert_eq!("invalid number at 2..7", error("PT1.2.3S"));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!("number too large at 1..21", error("P99999999999999999999D"));
+        assert_eq!("duration too long at 1..19", error("P999999999999999999Y"));
+        assert_eq!(
+            "duration too long at 12..26",
+            error("P200000000YT1000000000000H")
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use chrono_humanize::locale::{Context, PluralCategory, Unit};
+use chrono_humanize::Locale;
+
+/// Minimal locale speaking in abbreviations
+struct Terse;
+
+impl Locale for Terse {
+    fn plural(&self, _count: i64) -> PluralCategory {
+        PluralCategory::Other
+    }
+
+    fn unit(&self, unit: Unit, _plural: PluralCategory, _context: Context) -> Cow<'_, str> {
+        let text = match unit {
+            Unit::Nanos => "ns",
+            Unit::Micros => "us",
+            Unit::Millis => "ms",
+            Unit::Seconds => "s",
+            Unit::Minutes => "min",
+            Unit::Hours => "h",
+            Unit::Days => "d",
+            Unit::Weeks => "w",
+            Unit::Months => "mo",
+            Unit::Years => "y",
+        };
+        text.into()
+    }
+
+    fn now(&self) -> Cow<'_, str> {
+        "just now".into()
+    }
+
+    fn eternity(&self) -> Cow<'_, str> {
+        "forever".into()
+    }
+
+    fn join(&self, parts: Vec<String>) -> String {
+        parts.join(" ")
+    }
+
+    fn past(&self, text: String) -> String {
+        format!("-{}", text)
+    }
+
+    fn future(&self, text: String) -> String {
+        format!("+{}", text)
+    }
+}
+
+#[cfg(test)]
+mod custom {
+    use super::Terse;
+    use chrono::Duration;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    #[test]
+    fn now() {
+        let ht = HumanTime::from(Duration::zero());
+        let text = ht.to_text(&Terse, Accuracy::Rough, Tense::Present);
+        assert_eq!("just now", text);
+    }
+
+    #[test]
+    fn rough_past() {
+        let ht = HumanTime::from(Duration::hours(-3));
+        let text = ht.to_text(&Terse, Accuracy::Rough, Tense::Past);
+        assert_eq!("-3 h", text);
+    }
+
+    #[test]
+    fn rough_future() {
+        let ht = HumanTime::from(Duration::days(45));
+        let text = ht.to_text(&Terse, Accuracy::Rough, Tense::Future);
+        assert_eq!("+1 mo", text);
+    }
+
+    #[test]
+    fn precise_present() {
+        let ht = HumanTime::from(Duration::days(45) + Duration::minutes(2));
+        let text = ht.to_text(&Terse, Accuracy::Precise, Tense::Present);
+        assert_eq!("1 mo 2 w 1 d 2 min", text);
+    }
+}
+
+#[cfg(test)]
+mod english {
+    use chrono::Duration;
+    use chrono_humanize::locale::English;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    #[test]
+    fn same_as_to_text_en() {
+        let ht = HumanTime::from(Duration::seconds(1_234_567_890));
+        for accuracy in &[Accuracy::Rough, Accuracy::Precise] {
+            for tense in &[Tense::Past, Tense::Present, Tense::Future] {
+                let text = ht.to_text(&English, *accuracy, *tense);
+                assert_eq!(ht.to_text_en(*accuracy, *tense), text);
+            }
+        }
+    }
+
+    #[test]
+    fn precise_list() {
+        let ht = HumanTime::from(Duration::seconds(1_234_567_890));
+        let text = ht.to_text(&English, Accuracy::Precise, Tense::Future);
+        assert_eq!(
+            "in 39 years, 1 month, 3 weeks, 2 days, 23 hours, 31 minutes and 30 seconds",
+            text
+        );
+    }
+}
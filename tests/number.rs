@@ -0,0 +1,101 @@
+use chrono_humanize::locale::NumberFormat;
+
+#[cfg(test)]
+mod format {
+    use super::NumberFormat;
+
+    #[test]
+    fn grouping() {
+        let english = NumberFormat::default();
+        assert_eq!("0", english.integer(0));
+        assert_eq!("999", english.integer(999));
+        assert_eq!("1,000", english.integer(1000));
+        assert_eq!("-12,345,678", english.integer(-12_345_678));
+        assert_eq!("9,223,372,036,854,775,807", english.integer(i64::MAX));
+    }
+
+    #[test]
+    fn min_grouping() {
+        let spanish = NumberFormat::new(Some('.'), ',').with_min_grouping(2);
+        assert_eq!("1234", spanish.integer(1234));
+        assert_eq!("12.345", spanish.integer(12_345));
+
+        let huge = NumberFormat::default()
+            .with_grouping(200, 3)
+            .with_min_grouping(100);
+        assert_eq!("12345", huge.integer(12_345));
+    }
+
+    #[test]
+    fn indian() {
+        let indian = NumberFormat::default().with_grouping(3, 2);
+        assert_eq!("1,234", indian.integer(1234));
+        assert_eq!("1,23,45,678", indian.integer(12_345_678));
+    }
+
+    #[test]
+    fn no_grouping() {
+        assert_eq!("1234567", NumberFormat::new(None, '.').integer(1_234_567));
+    }
+
+    #[test]
+    fn digits() {
+        let thai = NumberFormat::default().with_digits('๐');
+        assert_eq!("๑,๒๓๔", thai.integer(1234));
+        let arabic = NumberFormat::new(Some('\u{66c}'), '\u{66b}').with_digits('\u{660}');
+        assert_eq!("١٬٢٣٤٫٥", arabic.decimal(12345, 1));
+    }
+
+    #[test]
+    fn decimal() {
+        let german = NumberFormat::new(Some('.'), ',');
+        assert_eq!("1.234,5", german.decimal(12345, 1));
+        assert_eq!("0,05", german.decimal(5, 2));
+        assert_eq!("-0,5", german.decimal(-5, 1));
+        assert_eq!("7", german.decimal(7, 0));
+    }
+}
+
+#[cfg(test)]
+mod english {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn grouping() {
+        let ht = HumanTime::from(Duration::days(-365 * 1234));
+        assert_eq!("1,234 years ago", format!("{}", ht));
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod locales {
+    use chrono::Duration;
+    use chrono_humanize::locale::{Arabic, French, Hindi, Spanish};
+    use chrono_humanize::{HumanTime, Locale};
+
+    fn text(locale: &dyn Locale, years: i64) -> String {
+        let ht = HumanTime::from(Duration::days(-365 * years));
+        format!("{}", ht.localized(locale))
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!("il y a 1\u{202f}234 ans", text(&French, 1234));
+        assert_eq!("hace 1234 años", text(&Spanish, 1234));
+        assert_eq!("hace 12.345 años", text(&Spanish, 12_345));
+    }
+
+    #[test]
+    fn native_digits() {
+        assert_eq!(
+            "منذ ١٬٢٣٤ سنة",
+            text(&Arabic::default().native_digits(true), 1234)
+        );
+        assert_eq!(
+            "१२,३४५ साल पहले",
+            text(&Hindi::default().native_digits(true), 12_345)
+        );
+        assert_eq!("12,345 साल पहले", text(&Hindi::default(), 12_345));
+    }
+}
@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod english {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    fn parse(text: &str) -> Duration {
+        text.parse::<HumanTime>().unwrap().into()
+    }
+
+    #[test]
+    fn now() {
+        assert_eq!(Duration::zero(), parse("now"));
+        assert_eq!(Duration::zero(), parse("  Now "));
+        assert_eq!(Duration::zero(), parse("in now"));
+        assert_eq!(Duration::zero(), parse("now ago"));
+    }
+
+    #[test]
+    fn rough() {
+        assert_eq!(Duration::hours(-1), parse("an hour ago"));
+        assert_eq!(Duration::weeks(3), parse("in 3 weeks"));
+        assert_eq!(Duration::days(30), parse("a month"));
+        assert_eq!(Duration::days(-365 * 1234), parse("1,234 years ago"));
+    }
+
+    #[test]
+    fn precise() {
+        assert_eq!(Duration::days(45), parse("1 month, 2 weeks and 1 day"));
+        assert_eq!(
+            Duration::seconds(-3601) - Duration::microseconds(5),
+            parse("1 hour, 1 second and 5 µs ago")
+        );
+        assert_eq!(
+            Duration::milliseconds(1500),
+            parse("in 1 second and 500 ms")
+        );
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    #[test]
+    fn precise() {
+        let durations = [
+            Duration::zero(),
+            Duration::nanoseconds(-7),
+            Duration::milliseconds(1500),
+            Duration::minutes(-62),
+            Duration::days(45),
+            Duration::days(-800) - Duration::hours(5) - Duration::nanoseconds(1),
+            Duration::weeks(52 * 2000),
+        ];
+        for &duration in &durations {
+            let text = format!("{:#}", HumanTime::from(duration));
+            let parsed: HumanTime = text.parse().unwrap();
+            assert_eq!(duration, Duration::from(parsed), "{}", text);
+        }
+    }
+
+    #[test]
+    fn rough() {
+        for &seconds in &[-100_000_000, -4000, -50, 0, 50, 4000, 100_000_000] {
+            let ht = HumanTime::from(Duration::seconds(seconds));
+            let parsed: HumanTime = ht.to_string().parse().unwrap();
+            assert_eq!(ht.to_string(), parsed.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use chrono_humanize::HumanTime;
+
+    fn error(text: &str) -> String {
+        text.parse::<HumanTime>().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("expected number at 0..0", error(""));
+        assert_eq!("expected unit at 2..4", error("3 hr ago"));
+        assert_eq!("expected number at 9..9", error("3 hours, "));
+        assert_eq!("unexpected text at 8..13", error("3 hours later"));
+        assert_eq!("unexpected character at 7..8", error("3 hours!"));
+        assert_eq!("both 'in' and 'ago' at 11..14", error("in 3 hours ago"));
+        assert_eq!("both 'in' and 'ago' at 7..10", error("in now ago"));
+        assert_eq!("unexpected text at 4..7", error("now and 3 hours"));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            "number too large at 0..20",
+            error("99999999999999999999 ns")
+        );
+        assert_eq!(
+            "duration too long at 0..23",
+            error("99999999999999999 years ago")
+        );
+    }
+}
+
+#[cfg(test)]
+mod span {
+    use chrono::Duration;
+    use chrono_humanize::HumanTime;
+
+    fn parse(text: &str) -> Duration {
+        HumanTime::parse_span(text).unwrap().into()
+    }
+
+    fn error(text: &str) -> String {
+        HumanTime::parse_span(text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(Duration::minutes(90), parse("1h30m"));
+        assert_eq!(Duration::days(5), parse("5d"));
+        assert_eq!(Duration::milliseconds(250), parse("250ms"));
+        assert_eq!(Duration::minutes(90), parse("90min"));
+        assert_eq!(Duration::days(30), parse("1M"));
+        assert_eq!(Duration::microseconds(3), parse("3µs"));
+        assert_eq!(Duration::zero(), parse("-0s"));
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(Duration::hours(52), parse("2 days 4 hours"));
+        assert_eq!(
+            Duration::weeks(-2) - Duration::seconds(1),
+            parse(" -2 Weeks 1 sec ")
+        );
+        assert_eq!(Duration::days(365 + 30), parse("1 year 1 month"));
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(Duration::minutes(90), parse("1.5h"));
+        assert_eq!(Duration::milliseconds(500), parse(".5s"));
+        assert_eq!(Duration::nanoseconds(1), parse("0.0000000019999s"));
+        assert_eq!(Duration::hours(-36), parse("-1.5d"));
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!("expected number at 0..0", error(""));
+        assert_eq!("expected number at 1..1", error("-"));
+        assert_eq!("expected number at 3..8", error("1h hours"));
+        assert_eq!("expected number at 2..3", error("1h,2m"));
+        assert_eq!("missing unit at 3..5", error("1h 30"));
+        assert_eq!("unknown unit at 4..5", error("2h 5x"));
+        assert_eq!("invalid number at 0..5", error("1.2.3h"));
+        assert_eq!("invalid number at 0..1", error(".h"));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!("number too large at 0..20", error("99999999999999999999d"));
+        assert_eq!("duration too long at 0..18", error("999999999999999999y"));
+        assert_eq!(
+            "duration too long at 11..21",
+            error("200000000y 200000000y")
+        );
+    }
+}
+
+#[cfg(all(test, feature = "locale-de", feature = "locale-fr"))]
+mod localized {
+    use chrono::Duration;
+    use chrono_humanize::locale::{French, German, Locale};
+    use chrono_humanize::HumanTime;
+
+    fn parse(text: &str, locale: &dyn Locale) -> Duration {
+        HumanTime::from_text(text, locale).unwrap().into()
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!(Duration::hours(-3), parse("il y a 3 heures", &French));
+        assert_eq!(Duration::days(30), parse("dans un mois", &French));
+        assert_eq!(Duration::zero(), parse("maintenant", &French));
+        assert_eq!(
+            Duration::days(-365 * 1234),
+            parse("Il y a 1\u{202f}234 ans", &French)
+        );
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!(Duration::days(-2), parse("vor 2 Tagen", &German));
+        assert_eq!(Duration::days(-1), parse("vor einem Tag", &German));
+        assert_eq!(Duration::days(2), parse("2 Tage", &German));
+        assert_eq!(
+            Duration::hours(3) + Duration::minutes(1),
+            parse("in 3 Stunden und 1 Minute", &German)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text| HumanTime::from_text(text, &German).unwrap_err().to_string();
+        assert_eq!("unrecognized period at 0..0", error(""));
+        assert_eq!("unrecognized period at 4..10", error("vor 2 Tagn"));
+        assert_eq!(
+            "unrecognized period at 11..23",
+            error("vor 2 Tagen und später")
+        );
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod locales {
+    use chrono::Duration;
+    use chrono_humanize::locale::Registry;
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    #[test]
+    fn round_trip() {
+        let durations = [
+            Duration::seconds(1),
+            Duration::seconds(50),
+            Duration::minutes(3),
+            Duration::hours(1),
+            Duration::hours(21),
+            Duration::days(1),
+            Duration::days(2),
+            Duration::days(5),
+            Duration::days(45),
+            Duration::days(800) + Duration::hours(5) + Duration::milliseconds(7),
+            Duration::days(365 * 1234),
+        ];
+        let registry = Registry::builtin();
+        // Pseudo-locales pad the text depending on its length
+        for tag in registry
+            .tags()
+            .filter(|tag| !["en-xa", "qps-ploc"].contains(tag))
+        {
+            let locale = registry.get(tag).unwrap();
+            for &duration in &durations {
+                for &tense in &[Tense::Past, Tense::Future] {
+                    let duration = match tense {
+                        Tense::Past => -duration,
+                        _ => duration,
+                    };
+                    let ht = HumanTime::from(duration);
+                    let text = ht.to_text(locale, Accuracy::Precise, tense);
+                    // Some CLDR languages write the past and the future the same
+                    let opposite = HumanTime::from(-duration);
+                    let other = match tense {
+                        Tense::Past => opposite.to_text(locale, Accuracy::Precise, Tense::Future),
+                        _ => opposite.to_text(locale, Accuracy::Precise, Tense::Past),
+                    };
+                    if text == other {
+                        continue;
+                    }
+                    let parsed = HumanTime::from_text(&text, locale)
+                        .unwrap_or_else(|err| panic!("{}: {:?} {}", tag, text, err));
+                    assert_eq!(duration, Duration::from(parsed), "{}: {}", tag, text);
+
+                    let text = ht.to_text(locale, Accuracy::Rough, tense);
+                    let parsed = HumanTime::from_text(&text, locale)
+                        .unwrap_or_else(|err| panic!("{}: {:?} {}", tag, text, err));
+                    assert_eq!(
+                        text,
+                        parsed.to_text(locale, Accuracy::Rough, tense),
+                        "{}",
+                        tag
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+#[cfg(feature = "all-locales")]
+#[macro_use]
+mod common;
+
+#[cfg(all(test, feature = "all-locales"))]
+mod categories {
+    use chrono_humanize::locale::*;
+    use chrono_humanize::Locale;
+
+    fn categories(locale: &dyn Locale, counts: &[i64]) -> Vec<PluralCategory> {
+        counts.iter().map(|n| locale.plural(*n)).collect()
+    }
+
+    #[test]
+    fn welsh() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 3, 4, 5, 6, 7, 11, 100];
+        let expected = [Zero, One, Two, Few, Other, Other, Many, Other, Other, Other];
+        assert_eq!(expected[..], categories(&Welsh, &counts)[..]);
+    }
+
+    #[test]
+    fn irish() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 3, 6, 7, 10, 11, 21];
+        let expected = [Other, One, Two, Few, Few, Many, Many, Other, Other];
+        assert_eq!(expected[..], categories(&Irish, &counts)[..]);
+    }
+
+    #[test]
+    fn lithuanian() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 9, 10, 11, 19, 21, 22, 111];
+        let expected = [Other, One, Few, Few, Other, Other, Other, One, Few, Other];
+        assert_eq!(expected[..], categories(&Lithuanian, &counts)[..]);
+    }
+
+    #[test]
+    fn latvian() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 10, 11, 19, 21, 111, 121];
+        let expected = [Zero, One, Other, Zero, Zero, Zero, One, Zero, One];
+        assert_eq!(expected[..], categories(&Latvian, &counts)[..]);
+    }
+
+    #[test]
+    fn arabic() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 3, 10, 11, 99, 100, 102, 103, 111];
+        let expected = [
+            Zero, One, Two, Few, Few, Many, Many, Other, Other, Few, Many,
+        ];
+        assert_eq!(expected[..], categories(&Arabic::default(), &counts)[..]);
+    }
+
+    #[test]
+    fn russian() {
+        use PluralCategory::*;
+        let counts = [0, 1, 2, 5, 11, 12, 21, 22, 25, 111, 112];
+        let expected = [Many, One, Few, Many, Many, Many, One, Few, Many, Many, Many];
+        assert_eq!(expected[..], categories(&Russian, &counts)[..]);
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod welsh {
+    use chrono::Duration;
+    use chrono_humanize::locale::Welsh;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Welsh,
+        now: Duration::zero(), Rough, Present, "nawr",
+        year_ago: Duration::days(-365), Precise, Past, "1 flwyddyn yn ôl",
+        two_years: Duration::days(2 * 365), Precise, Present, "2 flynedd",
+        three_years: Duration::days(3 * 365), Precise, Future, "ymhen 3 blynedd",
+        ten_years: Duration::days(10 * 365), Precise, Present, "10 mlynedd",
+        two_minutes: Duration::minutes(2), Precise, Present, "2 funud",
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod irish {
+    use chrono::Duration;
+    use chrono_humanize::locale::Irish;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Irish,
+        now: Duration::zero(), Rough, Present, "anois",
+        two_years_ago: Duration::days(-2 * 365), Rough, Past, "2 bhliain ó shin",
+        five_years: Duration::days(5 * 365), Rough, Present, "5 bliana",
+        seven_years: Duration::days(7 * 365), Rough, Future, "i gceann 7 mbliana",
+        twenty_years: Duration::days(20 * 365), Rough, Present, "20 bliain",
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod lithuanian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Lithuanian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Lithuanian,
+        now: Duration::zero(), Rough, Present, "dabar",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "prieš 3 valandas",
+        in_three_hours: Duration::hours(3), Rough, Future, "po 3 valandų",
+        three_hours: Duration::hours(3), Rough, Present, "3 valandos",
+        twenty_one_hours: Duration::hours(21), Precise, Past, "prieš 21 valandą",
+        eleven_hours: Duration::hours(11), Precise, Present, "11 valandų",
+        in_one_hour: Duration::hours(1), Precise, Future, "po 1 valandos",
+    }
+}
+
+#[cfg(all(test, feature = "all-locales"))]
+mod latvian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Latvian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Latvian,
+        now: Duration::zero(), Rough, Present, "tagad",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "pirms 3 stundām",
+        hour_ago: Duration::hours(-1), Precise, Past, "pirms 1 stundas",
+        twenty_one_hours: Duration::hours(21), Precise, Future, "pēc 21 stundas",
+        eleven_hours: Duration::hours(11), Precise, Present, "11 stundu",
+        three_hours: Duration::hours(3), Precise, Present, "3 stundas",
+    }
+}
+
+#[cfg(test)]
+mod rules {
+    use chrono_humanize::locale::*;
+    use PluralCategory::*;
+
+    fn rules(rules: &[(PluralCategory, &str)]) -> PluralRules {
+        rules
+            .iter()
+            .try_fold(PluralRules::new(), |rules, (category, rule)| {
+                rules.rule(*category, rule)
+            })
+            .unwrap()
+    }
+
+    fn select(rules: &PluralRules, number: &str) -> PluralCategory {
+        rules.select(number.parse::<PluralOperands>().unwrap())
+    }
+
+    #[test]
+    fn operands() {
+        let operands = "1.2c3".parse::<PluralOperands>().unwrap();
+        assert_eq!(
+            (1200.0, 1200, 0, 3),
+            (operands.n, operands.i, operands.v, operands.e)
+        );
+        let operands = "10.050".parse::<PluralOperands>().unwrap();
+        assert_eq!(
+            (10, 3, 2, 50, 5),
+            (operands.i, operands.v, operands.w, operands.f, operands.t)
+        );
+        assert!("1.".parse::<PluralOperands>().is_err());
+        assert!("abc".parse::<PluralOperands>().is_err());
+        let error = "1c100000000000".parse::<PluralOperands>().unwrap_err();
+        assert_eq!("exponent too large at 2..14", error.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "all-locales")]
+    fn same_as_builtin() {
+        // The CLDR rules of every shipped locale, the counts are never fractions
+        let cases: &[(&str, &[(PluralCategory, &str)])] = &[
+            (
+                "ar",
+                &[
+                    (Zero, "n = 0"),
+                    (One, "n = 1"),
+                    (Two, "n = 2"),
+                    (Few, "n % 100 = 3..10"),
+                    (Many, "n % 100 = 11..99"),
+                ],
+            ),
+            ("bn", &[(One, "i = 0 or n = 1")]),
+            (
+                "cs",
+                &[
+                    (One, "i = 1 and v = 0"),
+                    (Few, "i = 2..4 and v = 0"),
+                    (Many, "v != 0"),
+                ],
+            ),
+            (
+                "cy",
+                &[
+                    (Zero, "n = 0"),
+                    (One, "n = 1"),
+                    (Two, "n = 2"),
+                    (Few, "n = 3"),
+                    (Many, "n = 6"),
+                ],
+            ),
+            ("de", &[(One, "i = 1 and v = 0")]),
+            ("en", &[(One, "i = 1 and v = 0")]),
+            ("es", &[(One, "n = 1")]),
+            ("fi", &[(One, "i = 1 and v = 0")]),
+            ("fr", &[(One, "i = 0,1")]),
+            (
+                "ga",
+                &[
+                    (One, "n = 1"),
+                    (Two, "n = 2"),
+                    (Few, "n = 3..6"),
+                    (Many, "n = 7..10"),
+                ],
+            ),
+            (
+                "he",
+                &[
+                    (One, "i = 1 and v = 0 or i = 0 and v != 0"),
+                    (Two, "i = 2 and v = 0"),
+                ],
+            ),
+            ("hi", &[(One, "i = 0 or n = 1")]),
+            ("hu", &[(One, "n = 1")]),
+            ("it", &[(One, "i = 1 and v = 0")]),
+            ("ja", &[]),
+            ("ko", &[]),
+            (
+                "lt",
+                &[
+                    (One, "n % 10 = 1 and n % 100 != 11..19"),
+                    (Few, "n % 10 = 2..9 and n % 100 != 11..19"),
+                    (Many, "f != 0"),
+                ],
+            ),
+            (
+                "lv",
+                &[
+                    (Zero, "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19"),
+                    (One, "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f % 10 = 1"),
+                ],
+            ),
+            ("mr", &[(One, "n = 1")]),
+            ("nl", &[(One, "i = 1 and v = 0")]),
+            (
+                "pl",
+                &[
+                    (One, "i = 1 and v = 0"),
+                    (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+                    (Many, "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14"),
+                ],
+            ),
+            ("pt", &[(One, "i = 0..1")]),
+            ("pt-PT", &[(One, "i = 1 and v = 0")]),
+            (
+                "ro",
+                &[
+                    (One, "i = 1 and v = 0"),
+                    (Few, "v != 0 or n = 0 or n != 1 and n % 100 = 1..19"),
+                ],
+            ),
+            (
+                "ru",
+                &[
+                    (One, "v = 0 and i % 10 = 1 and i % 100 != 11 @integer 1, 21, 31, …"),
+                    (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+                    (Many, "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"),
+                ],
+            ),
+            ("tr", &[(One, "n = 1")]),
+            (
+                "uk",
+                &[
+                    (One, "v = 0 and i % 10 = 1 and i % 100 != 11"),
+                    (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+                    (Many, "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"),
+                ],
+            ),
+            ("zh", &[]),
+            ("zh-Hant", &[]),
+        ];
+        for (tag, cases) in cases {
+            let locale = Registry::builtin().get(tag).unwrap();
+            let rules = rules(cases);
+            for count in 0..=1000 {
+                assert_eq!(
+                    rules.select(count),
+                    locale.plural(count),
+                    "{} {}",
+                    tag,
+                    count
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn large_counts() {
+        let one = rules(&[(One, "i % 10 = 1")]);
+        assert_eq!(One, one.select(18_446_744_073_709_551_611_u64));
+        assert_eq!(Other, one.select(18_446_744_073_709_551_612_u64));
+        let russian = rules(&[
+            (One, "v = 0 and i % 10 = 1 and i % 100 != 11"),
+            (Few, "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+        ]);
+        assert_eq!(One, russian.select(18_446_744_073_709_551_601_u64));
+        assert_eq!(Few, russian.select(18_446_744_073_709_551_602_u64));
+        assert_eq!(Other, russian.select(18_446_744_073_709_551_615_u64));
+        assert_eq!(Few, select(&russian, "9007199254740993"));
+    }
+
+    #[test]
+    fn decimals() {
+        let english = rules(&[(One, "i = 1 and v = 0")]);
+        assert_eq!(One, select(&english, "1"));
+        assert_eq!(Other, select(&english, "1.0"));
+
+        let french = rules(&[(One, "i = 0,1")]);
+        assert_eq!(One, select(&french, "1.5"));
+        assert_eq!(Other, select(&french, "2.5"));
+    }
+
+    #[test]
+    fn in_and_within() {
+        let within = rules(&[(One, "n within 0..2")]);
+        let is_in = rules(&[(One, "n in 0..2")]);
+        assert_eq!(One, select(&within, "1.5"));
+        assert_eq!(Other, select(&is_in, "1.5"));
+        assert_eq!(One, select(&is_in, "2"));
+
+        let legacy = rules(&[(One, "n mod 10 is 1 and n mod 100 is not 11")]);
+        assert_eq!(One, legacy.select(21));
+        assert_eq!(Other, legacy.select(11));
+    }
+
+    #[test]
+    fn errors() {
+        let error = PluralRules::new().rule(One, "n = 1 and x = 2").unwrap_err();
+        assert_eq!(10..11, error.span());
+        let error = PluralRules::new().rule(One, "i % 0 = 1").unwrap_err();
+        assert_eq!(4..5, error.span());
+        let error = PluralRules::new().rule(One, "n = 5..2").unwrap_err();
+        assert_eq!(4..8, error.span());
+        let error = PluralRules::new().rule(One, "n = 1 or").unwrap_err();
+        assert_eq!("expected operand at 8..8", error.to_string());
+    }
+}
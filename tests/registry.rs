@@ -0,0 +1,113 @@
+#![cfg(feature = "all-locales")]
+
+#[cfg(test)]
+mod negotiation {
+    use chrono::Duration;
+    use chrono_humanize::locale::{fallback_chain, English, Registry};
+    use chrono_humanize::{Accuracy, HumanTime, Tense};
+
+    fn past(tag: &str) -> String {
+        let ht = HumanTime::from(Duration::hours(-3));
+        ht.to_text_for(tag, Accuracy::Rough, Tense::Past)
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!("3 hours ago", past("en"));
+        assert_eq!("il y a 3 heures", past("fr"));
+        assert_eq!("3時間前", past("ja"));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!("vor 3 Stunden", past("DE"));
+        assert_eq!("3小時前", past("zh-hant"));
+    }
+
+    #[test]
+    fn region_fallback() {
+        assert_eq!("vor 3 Stunden", past("de-CH"));
+        assert_eq!("hace 3 horas", past("es-419"));
+    }
+
+    #[test]
+    fn regional_variant() {
+        let ht = HumanTime::from(Duration::hours(3));
+        let brazil = ht.to_text_for("pt-BR", Accuracy::Rough, Tense::Future);
+        let portugal = ht.to_text_for("pt-PT", Accuracy::Rough, Tense::Future);
+        assert_eq!("em 3 horas", brazil);
+        assert_eq!("dentro de 3 horas", portugal);
+    }
+
+    #[test]
+    fn chinese_scripts() {
+        assert_eq!("3小时前", past("zh"));
+        assert_eq!("3小时前", past("zh-CN"));
+        assert_eq!("3小時前", past("zh-TW"));
+        assert_eq!("3小時前", past("zh-Hant-HK"));
+    }
+
+    #[test]
+    fn posix_names() {
+        assert_eq!("3 часа назад", past("ru_RU.UTF-8"));
+        assert_eq!("3 godziny temu", past("pl_PL@euro"));
+    }
+
+    #[test]
+    fn unknown_falls_back_to_english() {
+        assert_eq!("3 hours ago", past("tlh"));
+        assert_eq!("3 hours ago", past(""));
+    }
+
+    #[test]
+    fn chain() {
+        assert_eq!(
+            vec!["sr-latn-rs", "sr-latn", "sr"],
+            fallback_chain("sr-Latn-RS")
+        );
+        assert!(fallback_chain("").is_empty());
+    }
+
+    #[test]
+    fn custom_registry() {
+        let mut registry = Registry::new();
+        assert!(registry.get("en").is_none());
+        registry.register("en-x-terse", English);
+        assert!(registry.get("EN-X-TERSE").is_some());
+        assert_eq!(vec!["en-x-terse"], registry.tags().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn builtin_tags() {
+        let tags: Vec<_> = Registry::builtin().tags().collect();
+        assert!(tags.contains(&"en"));
+        assert!(tags.contains(&"pt-pt"));
+        assert!(tags.contains(&"zh-hant"));
+    }
+}
+
+#[cfg(test)]
+mod environment {
+    use std::env;
+
+    use chrono_humanize::locale::env_tag;
+
+    // All the environment manipulations are kept in one test,
+    // since the tests of one binary share the environment
+    #[test]
+    fn precedence() {
+        env::remove_var("LC_ALL");
+        env::remove_var("LC_TIME");
+        env::set_var("LANG", "de_DE.UTF-8");
+        assert_eq!(Some("de-de".to_string()), env_tag());
+
+        env::set_var("LC_TIME", "fr_CA");
+        assert_eq!(Some("fr-ca".to_string()), env_tag());
+
+        env::set_var("LC_ALL", "C.UTF-8");
+        assert_eq!(Some("en".to_string()), env_tag());
+
+        env::set_var("LC_ALL", "");
+        assert_eq!(Some("fr-ca".to_string()), env_tag());
+    }
+}
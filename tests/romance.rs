@@ -0,0 +1,99 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod french {
+    use chrono::Duration;
+    use chrono_humanize::locale::French;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { French,
+        now: Duration::zero(), Rough, Present, "maintenant",
+        zero: Duration::zero(), Precise, Present, "0 seconde",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "il y a 3 heures",
+        in_two_days: Duration::days(2), Rough, Future, "dans 2 jours",
+        hour_ago: Duration::hours(-1), Rough, Past, "il y a une heure",
+        in_a_month: Duration::days(30), Rough, Future, "dans un mois",
+        list: Duration::days(45), Precise, Present, "1 mois, 2 semaines et 1 jour",
+    }
+}
+
+#[cfg(test)]
+mod spanish {
+    use chrono::Duration;
+    use chrono_humanize::locale::Spanish;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Spanish,
+        now: Duration::zero(), Rough, Present, "ahora",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "hace 3 horas",
+        in_a_week: Duration::weeks(1), Rough, Future, "dentro de una semana",
+        year_ago: Duration::days(-365), Rough, Past, "hace un año",
+        list: Duration::days(45), Precise, Past, "hace 1 mes, 2 semanas y 1 día",
+    }
+}
+
+#[cfg(test)]
+mod italian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Italian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Italian,
+        now: Duration::zero(), Rough, Present, "adesso",
+        hour_ago: Duration::hours(-1), Rough, Past, "un'ora fa",
+        in_three_days: Duration::days(3), Rough, Future, "tra 3 giorni",
+        list: Duration::days(45), Precise, Future, "tra 1 mese, 2 settimane e 1 giorno",
+    }
+}
+
+#[cfg(test)]
+mod portuguese {
+    use chrono::Duration;
+    use chrono_humanize::locale::{EuropeanPortuguese, Portuguese};
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    mod brazilian {
+        use super::*;
+
+        locale_test! { Portuguese,
+            zero: Duration::zero(), Precise, Present, "0 segundo",
+            three_hours_ago: Duration::hours(-3), Rough, Past, "há 3 horas",
+            in_a_month: Duration::days(30), Rough, Future, "em um mês",
+            list: Duration::days(45), Precise, Past, "há 1 mês, 2 semanas e 1 dia",
+        }
+    }
+
+    mod european {
+        use super::*;
+
+        locale_test! { EuropeanPortuguese,
+            zero: Duration::zero(), Precise, Present, "0 segundos",
+            three_hours_ago: Duration::hours(-3), Rough, Past, "há 3 horas",
+            in_a_month: Duration::days(30), Rough, Future, "dentro de um mês",
+            in_an_hour: Duration::hours(1), Rough, Future, "dentro de uma hora",
+        }
+    }
+}
+
+#[cfg(test)]
+mod romanian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Romanian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Romanian,
+        now: Duration::zero(), Rough, Present, "acum",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "acum 3 ore",
+        hour_ago: Duration::hours(-1), Rough, Past, "acum o oră",
+        in_twenty_minutes: Duration::minutes(20), Precise, Future, "peste 20 de minute",
+        nineteen_minutes: Duration::minutes(19), Precise, Present, "19 minute",
+        hundred_years: Duration::days(365 * 100), Precise, Present, "100 de ani",
+        hundred_one_years: Duration::days(365 * 101), Precise, Present, "101 ani",
+        hundred_nineteen_years: Duration::days(365 * 119), Precise, Present, "119 ani",
+        hundred_twenty_years: Duration::days(365 * 120), Precise, Present, "120 de ani",
+        list: Duration::days(45), Precise, Present, "1 lună, 2 săptămâni și 1 zi",
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod arabic {
+    use chrono::Duration;
+    use chrono_humanize::locale::Arabic;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Arabic::default(),
+        now: Duration::zero(), Rough, Present, "الآن",
+        zero: Duration::zero(), Precise, Present, "0 ثانية",
+        hour_ago: Duration::hours(-1), Rough, Past, "منذ ساعة",
+        two_hours_ago: Duration::hours(-2), Rough, Past, "منذ ساعتين",
+        two_hours: Duration::hours(2), Precise, Present, "ساعتان",
+        three_hours_ago: Duration::hours(-3), Rough, Past, "منذ 3 ساعات",
+        eleven_hours: Duration::hours(11), Precise, Future, "بعد 11 ساعة",
+        eleven_days: Duration::days(11), Precise, Present, "أسبوع و4 أيام",
+        twelve_days: Duration::days(12), Rough, Present, "أسبوعان",
+        hundred_days: Duration::days(100), Precise, Present, "3 أشهر، أسبوع و3 أيام",
+        list: Duration::days(45), Precise, Past, "منذ شهر، أسبوعين ويوم",
+    }
+
+    #[test]
+    fn isolated() {
+        let ht = HumanTime::from(Duration::hours(-3));
+        let text = ht.to_text(&Arabic::default().isolated(true), Rough, Past);
+        assert_eq!("\u{2067}منذ \u{2066}3\u{2069} ساعات\u{2069}", text);
+
+        let arabic = Arabic::default().isolated(true).native_digits(true);
+        let text = ht.to_text(&arabic, Rough, Past);
+        assert_eq!("\u{2067}منذ \u{2066}٣\u{2069} ساعات\u{2069}", text);
+    }
+}
+
+#[cfg(test)]
+mod hebrew {
+    use chrono::Duration;
+    use chrono_humanize::locale::Hebrew;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Hebrew::default(),
+        now: Duration::zero(), Rough, Present, "עכשיו",
+        hour_ago: Duration::hours(-1), Rough, Past, "לפני שעה",
+        two_days_ago: Duration::days(-2), Rough, Past, "לפני יומיים",
+        in_two_weeks: Duration::weeks(2), Rough, Future, "בעוד שבועיים",
+        two_minutes: Duration::minutes(2), Precise, Present, "2 דקות",
+        three_hours: Duration::hours(3), Precise, Future, "בעוד 3 שעות",
+        list: Duration::days(45), Precise, Past, "לפני חודש, שבועיים ויום",
+    }
+
+    #[test]
+    fn isolated() {
+        let ht = HumanTime::from(Duration::hours(3));
+        let text = ht.to_text(&Hebrew::default().isolated(true), Rough, Future);
+        assert_eq!("\u{2067}בעוד \u{2066}3\u{2069} שעות\u{2069}", text);
+    }
+}
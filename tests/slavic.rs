@@ -0,0 +1,86 @@
+#![cfg(feature = "all-locales")]
+
+#[macro_use]
+mod common;
+
+#[cfg(test)]
+mod russian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Russian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Russian,
+        now: Duration::zero(), Rough, Present, "сейчас",
+        minute_ago: Duration::minutes(-1), Rough, Past, "минуту назад",
+        in_minute: Duration::minutes(1), Rough, Future, "через минуту",
+        one_minute: Duration::minutes(1), Precise, Present, "1 минута",
+        one_minute_ago: Duration::minutes(-1), Precise, Past, "1 минуту назад",
+        two_minutes: Duration::minutes(2), Precise, Future, "через 2 минуты",
+        five_minutes: Duration::minutes(5), Precise, Past, "5 минут назад",
+        eleven_minutes: Duration::minutes(11), Precise, Present, "11 минут",
+        twelve_minutes: Duration::minutes(12), Precise, Present, "12 минут",
+        twenty_one_minutes: Duration::minutes(21), Precise, Future, "через 21 минуту",
+        twenty_two_minutes: Duration::minutes(22), Precise, Future, "через 22 минуты",
+        an_hour: Duration::hours(1), Rough, Past, "час назад",
+        three_weeks: Duration::weeks(3), Rough, Future, "через 3 недели",
+        five_years: Duration::days(5 * 365), Rough, Past, "5 лет назад",
+        list: Duration::days(45), Precise, Past, "1 месяц, 2 недели и 1 день назад",
+    }
+}
+
+#[cfg(test)]
+mod ukrainian {
+    use chrono::Duration;
+    use chrono_humanize::locale::Ukrainian;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Ukrainian,
+        now: Duration::zero(), Rough, Present, "зараз",
+        hour_ago: Duration::hours(-1), Rough, Past, "годину тому",
+        in_minute: Duration::minutes(1), Rough, Future, "за хвилину",
+        one_hour: Duration::hours(1), Precise, Present, "1 година",
+        three_days: Duration::days(3), Precise, Past, "3 дні тому",
+        fourteen_days: Duration::days(14), Precise, Present, "2 тижні",
+        eleven_hours: Duration::hours(11), Precise, Future, "за 11 годин",
+        twenty_one_hours: Duration::hours(21), Precise, Future, "за 21 годину",
+        list: Duration::days(45), Precise, Future, "за 1 місяць, 2 тижні і 1 день",
+    }
+}
+
+#[cfg(test)]
+mod polish {
+    use chrono::Duration;
+    use chrono_humanize::locale::Polish;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Polish,
+        now: Duration::zero(), Rough, Present, "teraz",
+        minute_ago: Duration::minutes(-1), Rough, Past, "minutę temu",
+        one_minute: Duration::minutes(1), Precise, Present, "1 minuta",
+        two_minutes: Duration::minutes(2), Precise, Future, "za 2 minuty",
+        five_minutes: Duration::minutes(5), Precise, Past, "5 minut temu",
+        twenty_one_minutes: Duration::minutes(21), Precise, Past, "21 minut temu",
+        twenty_two_minutes: Duration::minutes(22), Precise, Past, "22 minuty temu",
+        two_years: Duration::days(2 * 365), Rough, Future, "za 2 lata",
+        list: Duration::days(45), Precise, Past, "1 miesiąc, 2 tygodnie i 1 dzień temu",
+    }
+}
+
+#[cfg(test)]
+mod czech {
+    use chrono::Duration;
+    use chrono_humanize::locale::Czech;
+    use chrono_humanize::{Accuracy::*, HumanTime, Tense::*};
+
+    locale_test! { Czech,
+        now: Duration::zero(), Rough, Present, "nyní",
+        minute_ago: Duration::minutes(-1), Rough, Past, "před minutou",
+        in_minute: Duration::minutes(1), Rough, Future, "za minutu",
+        one_minute: Duration::minutes(1), Precise, Present, "1 minuta",
+        three_minutes: Duration::minutes(3), Precise, Past, "před 3 minutami",
+        five_minutes: Duration::minutes(5), Precise, Future, "za 5 minut",
+        twenty_one_minutes: Duration::minutes(21), Precise, Present, "21 minut",
+        five_years: Duration::days(5 * 365), Rough, Past, "před 5 lety",
+        list: Duration::days(45), Precise, Past, "před 1 měsícem, 2 týdny a 1 dnem",
+    }
+}